@@ -0,0 +1,50 @@
+use bitcoin_from_scratch::finite_fields::FieldElement;
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use num_bigint::BigInt;
+
+// Operands shared by both backends: the generator's coordinates.
+const A_HEX: &[u8] = b"79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+const B_HEX: &[u8] = b"483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+fn field_benchmarks(c: &mut Criterion) {
+    let p = FieldElement::prime().clone();
+    let a_num = BigInt::parse_bytes(A_HEX, 16).unwrap();
+    let b_num = BigInt::parse_bytes(B_HEX, 16).unwrap();
+    let a = FieldElement::new(a_num.clone()).unwrap();
+    let b = FieldElement::new(b_num.clone()).unwrap();
+
+    let mut group = c.benchmark_group("field_mul");
+    group.bench_function("limbs", |bench| {
+        bench.iter(|| black_box(&a) * black_box(&b))
+    });
+    group.bench_function("bigint", |bench| {
+        bench.iter(|| (black_box(&a_num) * black_box(&b_num)) % &p)
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("field_add");
+    group.bench_function("limbs", |bench| {
+        bench.iter(|| black_box(&a) + black_box(&b))
+    });
+    group.bench_function("bigint", |bench| {
+        bench.iter(|| {
+            let mut sum = black_box(&a_num) + black_box(&b_num);
+            if sum >= p {
+                sum -= &p;
+            }
+            sum
+        })
+    });
+    group.finish();
+
+    let exponent = &p - BigInt::from(2);
+    let mut group = c.benchmark_group("field_inverse");
+    group.bench_function("limbs", |bench| bench.iter(|| black_box(&a).inverse()));
+    group.bench_function("bigint", |bench| {
+        bench.iter(|| black_box(&a_num).modpow(&exponent, &p))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, field_benchmarks);
+criterion_main!(benches);
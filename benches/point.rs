@@ -0,0 +1,25 @@
+use bitcoin_from_scratch::point::{G, Point};
+use bitcoin_from_scratch::scalar::Scalar;
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use num_bigint::BigInt;
+
+const K_HEX: &[u8] = b"aa5e28d6a97a2479a65527f7290311a3624d4cc0fa1578598ee3c2613bf99522";
+
+fn generator_benchmarks(c: &mut Criterion) {
+    let k = Scalar::new(BigInt::parse_bytes(K_HEX, 16).unwrap()).unwrap();
+    // Build the lazily initialized table outside the measurement
+    Point::mul_generator(&k);
+
+    let mut group = c.benchmark_group("mul_by_generator");
+    group.bench_function("mul_generator_table", |bench| {
+        bench.iter(|| Point::mul_generator(black_box(&k)))
+    });
+    group.bench_function("windowed_mul", |bench| bench.iter(|| &G * black_box(&k)));
+    group.bench_function("constant_time_ladder", |bench| {
+        bench.iter(|| G.mul_ct(black_box(&k)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, generator_benchmarks);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+//! A `#![no_std]` staticlib that links the library with `default-features = false`.
+//! If std leaked into the dependency graph, the build would fail with a duplicate
+//! `panic_impl` lang item, so a successful `cargo build` here is the check.
+
+#![no_std]
+
+extern crate alloc;
+
+use bitcoin_from_scratch::keys::PrivateKey;
+use bitcoin_from_scratch::point::{G, Point};
+use bitcoin_from_scratch::scalar::Scalar;
+use core::alloc::{GlobalAlloc, Layout};
+use core::panic::PanicInfo;
+
+// A stand-in allocator: the check only needs to link, never to run.
+struct NoAlloc;
+
+unsafe impl GlobalAlloc for NoAlloc {
+    unsafe fn alloc(&self, _layout: Layout) -> *mut u8 {
+        core::ptr::null_mut()
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
+
+#[global_allocator]
+static ALLOCATOR: NoAlloc = NoAlloc;
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    loop {}
+}
+
+/// Exercises the const generator, scalar multiplication and SEC encoding.
+#[unsafe(no_mangle)]
+pub extern "C" fn public_key_prefix(secret: &[u8; 32]) -> u8 {
+    let k = Scalar::reduce_be_bytes(secret);
+    let public_key: Point = Point::mul_generator(&k);
+    let doubled = &G + &G;
+    public_key.to_sec(true)[0] ^ doubled.to_sec(true)[0]
+}
+
+/// Exercises RFC 6979 signing and verification.
+#[unsafe(no_mangle)]
+pub extern "C" fn sign_and_verify(secret: &[u8; 32], hash: &[u8; 32]) -> bool {
+    let Ok(key) = PrivateKey::new(Scalar::reduce_be_bytes(secret)) else {
+        return false;
+    };
+    let z = Scalar::reduce_be_bytes(hash);
+    key.public_key().verify(&z, &key.sign(&z))
+}
@@ -0,0 +1,257 @@
+// Bitcoin addresses for single-key outputs.
+// P2PKH pays to hash160 of a SEC1 public key and is written in Base58Check behind a version
+// byte; the compressed and uncompressed encodings of one key give two different addresses.
+// P2SH pays to hash160 of a script; for P2SH-P2WPKH that script is the segwit v0 program
+// 0x00 0x14 <hash160 of the compressed key>. P2WPKH carries the same 20-byte key hash as a
+// native witness program, written in Bech32. Segwit only allows compressed keys.
+// P2WSH pays to the SHA-256 of a witness script, and P2TR (BIP341) to a 32-byte x-only taproot
+// output key, written in Bech32m as witness version 1.
+
+use crate::base58;
+use crate::bech32;
+use crate::error::Error;
+use crate::hash::{hash160, sha256};
+use crate::keys::PublicKey;
+use crate::script::{self, OP_0, OP_1, OP_EQUAL, OP_HASH160};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+/// The network an address belongs to, which selects its version bytes and Bech32 prefix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Network {
+    /// Bitcoin mainnet.
+    Mainnet,
+    /// Testnet and signet, which share the same prefixes.
+    Testnet,
+}
+
+impl Network {
+    /// Base58Check version byte of P2PKH addresses.
+    fn p2pkh_version(self) -> u8 {
+        match self {
+            Network::Mainnet => 0x00,
+            Network::Testnet => 0x6f,
+        }
+    }
+
+    /// Base58Check version byte of P2SH addresses.
+    fn p2sh_version(self) -> u8 {
+        match self {
+            Network::Mainnet => 0x05,
+            Network::Testnet => 0xc4,
+        }
+    }
+
+    /// Human-readable part of Bech32 addresses.
+    fn hrp(self) -> &'static str {
+        match self {
+            Network::Mainnet => "bc",
+            Network::Testnet => "tb",
+        }
+    }
+}
+
+/// A Bitcoin address: the network and the hash or key it pays to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Address {
+    /// Pay to public key hash.
+    P2pkh {
+        network: Network,
+        pubkey_hash: [u8; 20],
+    },
+    /// Pay to script hash.
+    P2sh {
+        network: Network,
+        script_hash: [u8; 20],
+    },
+    /// Pay to witness public key hash (segwit version 0).
+    P2wpkh {
+        network: Network,
+        pubkey_hash: [u8; 20],
+    },
+    /// Pay to witness script hash (segwit version 0).
+    P2wsh {
+        network: Network,
+        script_hash: [u8; 32],
+    },
+    /// Pay to taproot (segwit version 1).
+    P2tr {
+        network: Network,
+        output_key: [u8; 32],
+    },
+}
+
+impl Address {
+    /// Builds the P2PKH address of a key in its compressed or uncompressed SEC1 form.
+    pub fn p2pkh(public_key: &PublicKey, compressed: bool, network: Network) -> Self {
+        Address::P2pkh {
+            network,
+            pubkey_hash: hash160(&public_key.to_sec(compressed)),
+        }
+    }
+
+    /// Builds the P2SH-wrapped P2WPKH address of a key.
+    pub fn p2sh_p2wpkh(public_key: &PublicKey, network: Network) -> Self {
+        let mut redeem_script = [0u8; 22];
+        redeem_script[..2].copy_from_slice(&[0x00, 0x14]);
+        redeem_script[2..].copy_from_slice(&hash160(&public_key.to_sec(true)));
+        Address::P2sh {
+            network,
+            script_hash: hash160(&redeem_script),
+        }
+    }
+
+    /// Builds the native P2WPKH address of a key.
+    pub fn p2wpkh(public_key: &PublicKey, network: Network) -> Self {
+        Address::P2wpkh {
+            network,
+            pubkey_hash: hash160(&public_key.to_sec(true)),
+        }
+    }
+
+    /// Builds the P2WSH address of a witness script.
+    pub fn p2wsh(witness_script: &[u8], network: Network) -> Self {
+        Address::P2wsh {
+            network,
+            script_hash: sha256(witness_script),
+        }
+    }
+
+    /// Builds the P2TR address of a key with no script tree, which pays to the tweaked output
+    /// key (BIP341).
+    /// Returns the errors of `XOnlyPublicKey::tap_tweak`, which no known key triggers.
+    pub fn p2tr(public_key: &PublicKey, network: Network) -> Result<Self, Error> {
+        Ok(Address::P2tr {
+            network,
+            output_key: public_key.x_only().tap_tweak()?.to_bytes(),
+        })
+    }
+
+    /// Returns the network of the address.
+    pub fn network(&self) -> Network {
+        match self {
+            Address::P2pkh { network, .. }
+            | Address::P2sh { network, .. }
+            | Address::P2wpkh { network, .. }
+            | Address::P2wsh { network, .. }
+            | Address::P2tr { network, .. } => *network,
+        }
+    }
+
+    /// Returns the output script that locks coins sent to the address.
+    pub fn script_pubkey(&self) -> Vec<u8> {
+        let mut script = Vec::with_capacity(34);
+        match self {
+            Address::P2pkh { pubkey_hash, .. } => return script::p2pkh_script(pubkey_hash),
+            Address::P2sh { script_hash, .. } => {
+                script.push(OP_HASH160);
+                script::push_data(&mut script, script_hash);
+                script.push(OP_EQUAL);
+            }
+            Address::P2wpkh { pubkey_hash, .. } => {
+                script.push(OP_0);
+                script::push_data(&mut script, pubkey_hash);
+            }
+            Address::P2wsh { script_hash, .. } => {
+                script.push(OP_0);
+                script::push_data(&mut script, script_hash);
+            }
+            Address::P2tr { output_key, .. } => {
+                script.push(OP_1);
+                script::push_data(&mut script, output_key);
+            }
+        }
+        script
+    }
+}
+
+/// Formats the address in Base58Check (P2PKH, P2SH), Bech32 (P2WPKH, P2WSH) or Bech32m (P2TR).
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (version, hash) = match self {
+            Address::P2pkh {
+                network,
+                pubkey_hash,
+            } => (network.p2pkh_version(), pubkey_hash),
+            Address::P2sh {
+                network,
+                script_hash,
+            } => (network.p2sh_version(), script_hash),
+            Address::P2wpkh {
+                network,
+                pubkey_hash,
+            } => return f.write_str(&bech32::encode_segwit(network.hrp(), 0, pubkey_hash)),
+            Address::P2wsh {
+                network,
+                script_hash,
+            } => return f.write_str(&bech32::encode_segwit(network.hrp(), 0, script_hash)),
+            Address::P2tr {
+                network,
+                output_key,
+            } => return f.write_str(&bech32::encode_segwit(network.hrp(), 1, output_key)),
+        };
+        let mut payload = [0u8; 21];
+        payload[0] = version;
+        payload[1..].copy_from_slice(hash);
+        f.write_str(&base58::encode_check(&payload))
+    }
+}
+
+/// Parses a mainnet or testnet P2PKH, P2SH, P2WPKH, P2WSH or P2TR address.
+/// Returns `Error::InvalidBase58`, `Error::InvalidBech32` or `Error::InvalidChecksum` for
+/// malformed strings, `Error::InvalidLength` or `Error::InvalidPrefix` for a Base58 payload that
+/// is not a 20-byte hash behind a known version byte, and `Error::UnsupportedAddress` for other
+/// networks and witness programs.
+impl FromStr for Address {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Bech32 addresses start with the human-readable part and the separator '1'
+        let lowercase = s.to_ascii_lowercase();
+        if lowercase.starts_with("bc1") || lowercase.starts_with("tb1") {
+            let (hrp, version, program) = bech32::decode_segwit(s)?;
+            let network = match hrp.as_str() {
+                "bc" => Network::Mainnet,
+                "tb" => Network::Testnet,
+                _ => return Err(Error::UnsupportedAddress),
+            };
+            return match (version, program.len()) {
+                (0, 20) => Ok(Address::P2wpkh {
+                    network,
+                    pubkey_hash: program.try_into().unwrap(),
+                }),
+                (0, 32) => Ok(Address::P2wsh {
+                    network,
+                    script_hash: program.try_into().unwrap(),
+                }),
+                (1, 32) => Ok(Address::P2tr {
+                    network,
+                    output_key: program.try_into().unwrap(),
+                }),
+                _ => Err(Error::UnsupportedAddress),
+            };
+        }
+
+        let payload = base58::decode_check(s)?;
+        if payload.len() != 21 {
+            return Err(Error::InvalidLength(payload.len()));
+        }
+        let hash: [u8; 20] = payload[1..].try_into().unwrap();
+        for network in [Network::Mainnet, Network::Testnet] {
+            if payload[0] == network.p2pkh_version() {
+                return Ok(Address::P2pkh {
+                    network,
+                    pubkey_hash: hash,
+                });
+            }
+            if payload[0] == network.p2sh_version() {
+                return Ok(Address::P2sh {
+                    network,
+                    script_hash: hash,
+                });
+            }
+        }
+        Err(Error::InvalidPrefix(payload[0]))
+    }
+}
@@ -0,0 +1,176 @@
+use crate::address::{Address, Network};
+use crate::error::Error;
+use crate::keys::PublicKey;
+use crate::point::G;
+use crate::test_util::bip322_key;
+
+//------------------------------
+// Encoding Tests
+//------------------------------
+
+#[test]
+fn test_addresses_of_generator() {
+    // Test the well-known P2PKH and P2WPKH addresses of the key 1, whose public key is G.
+    let key = PublicKey::from_point(G).unwrap();
+    let cases = [
+        (
+            Address::p2pkh(&key, true, Network::Mainnet),
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+        ),
+        (
+            Address::p2pkh(&key, false, Network::Mainnet),
+            "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm",
+        ),
+        (
+            Address::p2wpkh(&key, Network::Mainnet),
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+        ),
+    ];
+    for (address, expected) in cases {
+        assert_eq!(address.to_string(), expected);
+        assert_eq!(expected.parse::<Address>().unwrap(), address);
+    }
+}
+
+#[test]
+fn test_addresses_of_bip322_key() {
+    // Test the five single-key addresses of the BIP322 test key.
+    let key = bip322_key();
+    let public_key = key.public_key();
+    let cases = [
+        (
+            Address::p2pkh(public_key, false, Network::Mainnet),
+            "169ojqRJ3d4f7aNMu86nAAwGJyeykmByFU",
+        ),
+        (
+            Address::p2pkh(public_key, true, Network::Mainnet),
+            "14vV3aCHBeStb5bkenkNHbe2YAFinYdXgc",
+        ),
+        (
+            Address::p2sh_p2wpkh(public_key, Network::Mainnet),
+            "37qyp7jQAzqb2rCBpMvVtLDuuzKAUCVnJb",
+        ),
+        (
+            Address::p2wpkh(public_key, Network::Mainnet),
+            "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l",
+        ),
+        (
+            Address::p2tr(public_key, Network::Mainnet).unwrap(),
+            "bc1ppv609nr0vr25u07u95waq5lucwfm6tde4nydujnu8npg4q75mr5sxq8lt3",
+        ),
+    ];
+    for (address, expected) in cases {
+        assert_eq!(address.to_string(), expected);
+        assert_eq!(expected.parse::<Address>().unwrap(), address);
+    }
+}
+
+#[test]
+fn test_testnet_addresses() {
+    // Test that testnet addresses use their own version bytes and human-readable part.
+    let key = bip322_key();
+    let public_key = key.public_key();
+    for address in [
+        Address::p2pkh(public_key, true, Network::Testnet),
+        Address::p2sh_p2wpkh(public_key, Network::Testnet),
+        Address::p2wpkh(public_key, Network::Testnet),
+    ] {
+        let encoded = address.to_string();
+        assert!(
+            ["m", "n", "2", "tb1q"]
+                .iter()
+                .any(|p| encoded.starts_with(p))
+        );
+        assert_eq!(encoded.parse::<Address>().unwrap(), address);
+        assert_eq!(address.network(), Network::Testnet);
+    }
+}
+
+#[test]
+fn test_script_addresses() {
+    // Test the BIP173 P2WSH example and the BIP86-style taproot address whose output key is
+    // G's x-coordinate, with their output scripts.
+    let cases = [
+        (
+            "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7",
+            "00201863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262",
+        ),
+        (
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+            "512079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        ),
+    ];
+    for (encoded, script_pubkey) in cases {
+        let address = encoded.parse::<Address>().unwrap();
+        assert_eq!(address.to_string(), encoded);
+        assert_eq!(crate::hex::encode(&address.script_pubkey()), script_pubkey);
+    }
+    let witness_script = crate::hex::decode(
+        "210279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798ac",
+    )
+    .unwrap();
+    assert_eq!(
+        Address::p2wsh(&witness_script, Network::Testnet).to_string(),
+        "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7"
+    );
+}
+
+#[test]
+fn test_key_address_scripts() {
+    // Test the output scripts of the key-hash address types.
+    let key = PublicKey::from_point(G).unwrap();
+    let hash = "751e76e8199196d454941c45d1b3a323f1433bd6";
+    let cases = [
+        (
+            Address::p2pkh(&key, true, Network::Mainnet),
+            format!("76a914{}88ac", hash),
+        ),
+        (
+            Address::p2wpkh(&key, Network::Mainnet),
+            format!("0014{}", hash),
+        ),
+    ];
+    for (address, script_pubkey) in cases {
+        assert_eq!(crate::hex::encode(&address.script_pubkey()), script_pubkey);
+    }
+    let p2sh = Address::p2sh_p2wpkh(&key, Network::Mainnet).script_pubkey();
+    assert_eq!(
+        (p2sh[0], p2sh[1], p2sh[22], p2sh.len()),
+        (0xa9, 0x14, 0x87, 23)
+    );
+}
+
+//------------------------------
+// Parsing Error Tests
+//------------------------------
+
+#[test]
+fn test_parse_errors() {
+    // Test that bad checksums, unknown versions and unsupported witness programs are rejected.
+    let cases = [
+        ("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMJ", Error::InvalidChecksum),
+        ("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAM0", Error::InvalidBase58),
+        (
+            "QLbz7JHiBTspS962RLKV8GndWFwjA5K66",
+            Error::InvalidPrefix(0x01),
+        ),
+        (
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5",
+            Error::InvalidChecksum,
+        ),
+        (
+            "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y",
+            Error::UnsupportedAddress,
+        ),
+        (
+            "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080",
+            Error::InvalidBase58,
+        ),
+    ];
+    for (address, error) in cases {
+        assert_eq!(address.parse::<Address>(), Err(error), "{}", address);
+    }
+    // A valid Base58Check payload that is one byte too long
+    let long = crate::base58::encode_check(&[0u8; 22]);
+    assert_eq!(long.parse::<Address>(), Err(Error::InvalidLength(22)));
+}
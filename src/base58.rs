@@ -0,0 +1,75 @@
+// Base58Check (the encoding of legacy addresses and WIF private keys): the payload followed by
+// the first four bytes of its hash256, written in base 58 with the alphabet below, which leaves
+// out 0, O, I and l. Each leading zero byte becomes a leading '1'.
+
+use crate::error::Error;
+use crate::hash::hash256;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encodes the payload with its four-byte checksum in Base58.
+pub(crate) fn encode_check(payload: &[u8]) -> String {
+    let mut bytes = payload.to_vec();
+    bytes.extend_from_slice(&hash256(payload)[..4]);
+
+    // Repeated division of the big-endian number by 58, one base-58 digit per step
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for &byte in &bytes {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    let mut encoded = String::with_capacity(zeros + digits.len());
+    encoded.extend(core::iter::repeat_n('1', zeros));
+    encoded.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&digit| ALPHABET[digit as usize] as char),
+    );
+    encoded
+}
+
+/// Decodes a Base58Check string and returns the payload without the checksum.
+/// Returns `Error::InvalidBase58` for characters outside the alphabet or inputs too short to
+/// hold a checksum, and `Error::InvalidChecksum` if the checksum does not match.
+pub(crate) fn decode_check(encoded: &str) -> Result<Vec<u8>, Error> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(encoded.len());
+    for c in encoded.bytes() {
+        let mut carry = ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or(Error::InvalidBase58)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    let zeros = encoded.bytes().take_while(|&c| c == b'1').count();
+    bytes.extend(core::iter::repeat_n(0, zeros));
+    bytes.reverse();
+
+    if bytes.len() < 4 {
+        return Err(Error::InvalidBase58);
+    }
+    let (payload, checksum) = bytes.split_at(bytes.len() - 4);
+    if hash256(payload)[..4] != *checksum {
+        return Err(Error::InvalidChecksum);
+    }
+    Ok(payload.to_vec())
+}
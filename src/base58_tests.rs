@@ -0,0 +1,36 @@
+use crate::base58::{decode_check, encode_check};
+use crate::error::Error;
+use proptest::prelude::*;
+
+#[test]
+fn test_encode_check_leading_zeros() {
+    // Test that each leading zero byte becomes a '1', as in the all-zero P2PKH address.
+    let encoded = encode_check(&[0u8; 21]);
+    assert_eq!(encoded, "1111111111111111111114oLvT2");
+    assert_eq!(decode_check(&encoded).unwrap(), vec![0u8; 21]);
+}
+
+#[test]
+fn test_decode_check_errors() {
+    // Test that characters outside the alphabet, short inputs and bad checksums are rejected.
+    assert_eq!(
+        decode_check("1111111111111111111114oLvT0"),
+        Err(Error::InvalidBase58)
+    );
+    assert_eq!(decode_check("1I"), Err(Error::InvalidBase58));
+    assert_eq!(decode_check(""), Err(Error::InvalidBase58));
+    assert_eq!(
+        decode_check("1111111111111111111114oLvT3"),
+        Err(Error::InvalidChecksum)
+    );
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn prop_base58_round_trip(payload in proptest::collection::vec(any::<u8>(), 0..40)) {
+        // Test that any payload, including ones with leading zeros, round-trips.
+        prop_assert_eq!(decode_check(&encode_check(&payload)).unwrap(), payload);
+    }
+}
@@ -0,0 +1,132 @@
+// Bech32 and Bech32m (BIP173, BIP350), the encodings of native segwit addresses:
+// a human-readable part such as "bc", the separator '1', then 5-bit groups holding the witness
+// version, the witness program and a six-character checksum. Version 0 uses the original
+// Bech32 checksum constant; versions 1 and above use Bech32m.
+
+use crate::error::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+/// Computes the BCH checksum polynomial over 5-bit values.
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+    let mut checksum = 1u32;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x1ff_ffff) << 5) ^ value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+/// Expands the human-readable part for checksumming: high bits, a zero, then low bits.
+fn expand_hrp(hrp: &str) -> impl Iterator<Item = u8> + '_ {
+    hrp.bytes()
+        .map(|c| c >> 5)
+        .chain(core::iter::once(0))
+        .chain(hrp.bytes().map(|c| c & 31))
+}
+
+/// Regroups bits from `from`-bit to `to`-bit values. With `pad`, leftover bits are zero-padded;
+/// without it, leftover bits must be fewer than `from` and zero, or `None` is returned.
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    let max = (1u32 << to) - 1;
+    let mut out = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+    for &value in data {
+        acc = (acc << from) | value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & max) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || (acc << (to - bits)) & max != 0 {
+        return None;
+    }
+    Some(out)
+}
+
+/// Returns the checksum constant for a witness version.
+fn checksum_const(version: u8) -> u32 {
+    if version == 0 {
+        BECH32_CONST
+    } else {
+        BECH32M_CONST
+    }
+}
+
+/// Encodes a segwit address from its human-readable part, witness version and program.
+pub(crate) fn encode_segwit(hrp: &str, version: u8, program: &[u8]) -> String {
+    let mut data = Vec::with_capacity(1 + program.len() * 8 / 5 + 7);
+    data.push(version);
+    data.extend(convert_bits(program, 8, 5, true).expect("padding never fails"));
+    let checksum = polymod(expand_hrp(hrp).chain(data.iter().copied()).chain([0; 6]))
+        ^ checksum_const(version);
+    data.extend((0..6).map(|i| ((checksum >> (5 * (5 - i))) & 31) as u8));
+
+    let mut address = String::with_capacity(hrp.len() + 1 + data.len());
+    address.push_str(hrp);
+    address.push('1');
+    address.extend(data.iter().map(|&d| CHARSET[d as usize] as char));
+    address
+}
+
+/// Decodes a segwit address into its lowercase human-readable part, witness version and program.
+/// Checks the character set, mixed case, the checksum variant required by the version, and
+/// the program lengths allowed by BIP141. Returns `Error::InvalidBech32` for malformed strings
+/// and `Error::InvalidChecksum` if the checksum does not match.
+pub(crate) fn decode_segwit(address: &str) -> Result<(String, u8, Vec<u8>), Error> {
+    let has_lower = address.bytes().any(|c| c.is_ascii_lowercase());
+    let has_upper = address.bytes().any(|c| c.is_ascii_uppercase());
+    if (has_lower && has_upper) || address.len() > 90 {
+        return Err(Error::InvalidBech32);
+    }
+    let address = address.to_ascii_lowercase();
+    let (hrp, data) = address.rsplit_once('1').ok_or(Error::InvalidBech32)?;
+    if hrp.is_empty() || data.len() < 7 || !hrp.bytes().all(|c| (33..=126).contains(&c)) {
+        return Err(Error::InvalidBech32);
+    }
+    let data = data
+        .bytes()
+        .map(|c| CHARSET.iter().position(|&d| d == c).map(|d| d as u8))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or(Error::InvalidBech32)?;
+
+    let version = data[0];
+    if version > 16 {
+        return Err(Error::InvalidBech32);
+    }
+    if polymod(expand_hrp(hrp).chain(data.iter().copied())) != checksum_const(version) {
+        return Err(Error::InvalidChecksum);
+    }
+    let program =
+        convert_bits(&data[1..data.len() - 6], 5, 8, false).ok_or(Error::InvalidBech32)?;
+    let valid_length = match version {
+        0 => program.len() == 20 || program.len() == 32,
+        _ => (2..=40).contains(&program.len()),
+    };
+    if !valid_length {
+        return Err(Error::InvalidBech32);
+    }
+    Ok((String::from(hrp), version, program))
+}
@@ -0,0 +1,86 @@
+use crate::bech32::{decode_segwit, encode_segwit};
+use crate::error::Error;
+use crate::test_util::hex;
+
+//------------------------------
+// Valid Address Tests
+//------------------------------
+
+#[test]
+fn test_segwit_v0_vectors() {
+    // Test the BIP173 P2WPKH and P2WSH examples, including an uppercase address.
+    let cases = [
+        (
+            "bc",
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            "751e76e8199196d454941c45d1b3a323f1433bd6",
+        ),
+        (
+            "tb",
+            "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7",
+            "1863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262",
+        ),
+    ];
+    for (hrp, address, program) in cases {
+        assert_eq!(encode_segwit(hrp, 0, &hex(program)), address);
+        let expected = (hrp.to_string(), 0, hex(program));
+        assert_eq!(decode_segwit(address).unwrap(), expected);
+        assert_eq!(decode_segwit(&address.to_uppercase()).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_segwit_v1_vectors() {
+    // Test BIP350 Bech32m addresses: a 40-byte version 1 program and the taproot key of G.
+    let cases = [
+        (
+            "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y",
+            "751e76e8199196d454941c45d1b3a323f1433bd6751e76e8199196d454941c45d1b3a323f1433bd6",
+        ),
+        (
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        ),
+    ];
+    for (address, program) in cases {
+        assert_eq!(encode_segwit("bc", 1, &hex(program)), address);
+        assert_eq!(
+            decode_segwit(address).unwrap(),
+            ("bc".to_string(), 1, hex(program))
+        );
+    }
+}
+
+//------------------------------
+// Invalid Address Tests
+//------------------------------
+
+#[test]
+fn test_decode_segwit_wrong_checksum_variant() {
+    // Test that version 0 needs Bech32 and version 1 needs Bech32m (BIP350).
+    let v0_as_bech32m = encode_segwit("bc", 1, &hex("751e76e8199196d454941c45d1b3a323f1433bd6"))
+        .replacen("bc1p", "bc1q", 1);
+    assert_eq!(decode_segwit(&v0_as_bech32m), Err(Error::InvalidChecksum));
+    assert_eq!(
+        decode_segwit("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd"),
+        Err(Error::InvalidChecksum)
+    );
+}
+
+#[test]
+fn test_decode_segwit_malformed() {
+    // Test that mixed case, missing separators, bad characters and bad lengths are rejected.
+    let cases = [
+        "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kV8F3T4", // mixed case
+        "bcqw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",  // no separator
+        "1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",   // empty human-readable part
+        "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3tb", // 'b' is not in the character set
+        "bc1gmk9yu",                                  // empty data
+    ];
+    for case in cases {
+        assert_eq!(decode_segwit(case), Err(Error::InvalidBech32), "{}", case);
+    }
+    // A 16-byte version 0 program is neither P2WPKH nor P2WSH
+    let short = encode_segwit("bc", 0, &[0u8; 16]);
+    assert_eq!(decode_segwit(&short), Err(Error::InvalidBech32));
+}
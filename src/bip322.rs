@@ -0,0 +1,271 @@
+// BIP322 generic signed messages: a proof that the signer could spend coins sent to an
+// address, made by signing a virtual transaction instead of a bare message hash, so the same
+// scheme works for any script.
+// to_spend has one input spending the null outpoint with scriptSig OP_0 <message hash>, where
+// the message hash is the tagged hash "BIP0322-signed-message" of the message, and one output
+// of zero satoshis locked by the address's script. to_sign spends that output and has a single
+// OP_RETURN output. Both have version 0, sequence 0 and lock time 0.
+// The "simple" format is the Base64 witness stack of to_sign's input; the "full" format is the
+// whole Base64 to_sign transaction, needed when the input also has a scriptSig (P2SH).
+//
+// Signing supports P2WPKH and P2TR key-path spends (simple and full) and P2SH-P2WPKH (full).
+// Verification also runs P2WSH witness scripts and P2SH-wrapped P2WSH through the script
+// interpreter. P2TR proofs are BIP340 signatures by the tweaked output key (BIP341), without a
+// script tree. P2PKH proofs use the legacy format instead (see the `message` module), so they
+// are reported as unsupported.
+
+use crate::address::Address;
+use crate::error::Error;
+use crate::hash::{hash160, tagged_hash};
+use crate::keys::PrivateKey;
+use crate::scalar::Scalar;
+use crate::schnorr::{SchnorrSignature, XOnlyPublicKey};
+use crate::script::{self, OP_0, OP_RETURN};
+use crate::transaction::{
+    OutPoint, SIGHASH_ALL, SIGHASH_DEFAULT, Transaction, TxIn, TxOut, parse_witness,
+    serialize_witness,
+};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+/// Computes the BIP322 message hash: the tagged hash "BIP0322-signed-message" of the message.
+pub fn message_hash(message: &[u8]) -> [u8; 32] {
+    tagged_hash("BIP0322-signed-message", &[message])
+}
+
+/// Builds the virtual transaction whose only output the proof spends.
+pub(crate) fn to_spend(script_pubkey: &[u8], message: &[u8]) -> Transaction {
+    let mut script_sig = vec![OP_0];
+    script::push_data(&mut script_sig, &message_hash(message));
+    Transaction {
+        version: 0,
+        inputs: vec![TxIn {
+            previous_output: OutPoint {
+                txid: [0; 32],
+                vout: 0xffff_ffff,
+            },
+            script_sig,
+            sequence: 0,
+            witness: Vec::new(),
+        }],
+        outputs: vec![TxOut {
+            value: 0,
+            script_pubkey: script_pubkey.to_vec(),
+        }],
+        lock_time: 0,
+    }
+}
+
+/// Builds the unsigned virtual transaction that spends the output of `to_spend`.
+pub(crate) fn to_sign(to_spend: &Transaction) -> Transaction {
+    Transaction {
+        version: 0,
+        inputs: vec![TxIn {
+            previous_output: OutPoint {
+                txid: to_spend.txid(),
+                vout: 0,
+            },
+            script_sig: Vec::new(),
+            sequence: 0,
+            witness: Vec::new(),
+        }],
+        outputs: vec![TxOut {
+            value: 0,
+            script_pubkey: vec![OP_RETURN],
+        }],
+        lock_time: 0,
+    }
+}
+
+impl PrivateKey {
+    /// Signs a message for a P2WPKH or P2TR address in the BIP322 simple format (a Base64
+    /// witness).
+    /// Returns `Error::UnsupportedAddress` for other address types and `Error::KeyMismatch` if
+    /// the key does not belong to the address.
+    pub fn sign_bip322_simple(&self, address: &Address, message: &str) -> Result<String, Error> {
+        if !matches!(address, Address::P2wpkh { .. } | Address::P2tr { .. }) {
+            return Err(Error::UnsupportedAddress);
+        }
+        let to_sign = self.sign_bip322(address, message)?;
+        Ok(BASE64.encode(serialize_witness(&to_sign.inputs[0].witness)))
+    }
+
+    /// Signs a message for a P2WPKH, P2SH-P2WPKH or P2TR address in the BIP322 full format
+    /// (a Base64 transaction).
+    /// Returns `Error::UnsupportedAddress` for other address types and `Error::KeyMismatch` if
+    /// the key does not belong to the address.
+    pub fn sign_bip322_full(&self, address: &Address, message: &str) -> Result<String, Error> {
+        let to_sign = self.sign_bip322(address, message)?;
+        Ok(BASE64.encode(to_sign.serialize()))
+    }
+
+    /// Builds and signs to_sign, with a low-R signature as Bitcoin Core makes, or for P2TR a
+    /// SIGHASH_DEFAULT signature by the tweaked key.
+    fn sign_bip322(&self, address: &Address, message: &str) -> Result<Transaction, Error> {
+        let network = address.network();
+        if let Address::P2tr { .. } = address {
+            if *address != Address::p2tr(self.public_key(), network)? {
+                return Err(Error::KeyMismatch);
+            }
+            return self.sign_bip322_taproot(address, message);
+        }
+        let script_sig = match address {
+            Address::P2wpkh { .. } if *address == Address::p2wpkh(self.public_key(), network) => {
+                Vec::new()
+            }
+            Address::P2sh { .. }
+                if *address == Address::p2sh_p2wpkh(self.public_key(), network) =>
+            {
+                let redeem_script = Address::p2wpkh(self.public_key(), network).script_pubkey();
+                let mut script_sig = Vec::with_capacity(23);
+                script::push_data(&mut script_sig, &redeem_script);
+                script_sig
+            }
+            Address::P2wpkh { .. } | Address::P2sh { .. } => return Err(Error::KeyMismatch),
+            _ => return Err(Error::UnsupportedAddress),
+        };
+
+        let public_key = self.public_key().to_sec(true);
+        let to_spend = to_spend(&address.script_pubkey(), message.as_bytes());
+        let mut to_sign = to_sign(&to_spend);
+        to_sign.inputs[0].script_sig = script_sig;
+        let script_code = script::p2pkh_script(&hash160(&public_key));
+        let z = Scalar::reduce_be_bytes(&to_sign.segwit_v0_sighash(0, &script_code, 0));
+        let mut signature = self.sign_low_r(&z).to_der();
+        signature.push(SIGHASH_ALL);
+        to_sign.inputs[0].witness = vec![signature, public_key];
+        Ok(to_sign)
+    }
+
+    /// Builds to_sign for a P2TR address of this key and signs its key-path spend. The aux_rand
+    /// of the signature is all zeros, so proofs are deterministic like the ECDSA ones.
+    fn sign_bip322_taproot(&self, address: &Address, message: &str) -> Result<Transaction, Error> {
+        let to_spend = to_spend(&address.script_pubkey(), message.as_bytes());
+        let mut to_sign = to_sign(&to_spend);
+        let sighash = to_sign.taproot_key_spend_sighash(0, &to_spend.outputs, SIGHASH_DEFAULT);
+        let signature = self.tap_tweak()?.sign_schnorr(&sighash, &[0; 32])?;
+        to_sign.inputs[0].witness = vec![signature.to_bytes().to_vec()];
+        Ok(to_sign)
+    }
+}
+
+/// Verifies a BIP322 simple signature (a Base64 witness stack) for a P2WPKH, P2WSH or P2TR
+/// address.
+/// Returns `Ok(false)` if the witness does not prove control of the address.
+/// Returns the address parsing errors of `Address::from_str`, `Error::UnsupportedAddress` for
+/// P2PKH addresses and for P2SH addresses, whose proofs need a scriptSig and so the full
+/// format, `Error::InvalidBase64` if the signature is not Base64, and
+/// `Error::InvalidTransaction` if it is not a witness stack.
+pub fn verify_simple(address: &str, signature: &str, message: &str) -> Result<bool, Error> {
+    let address: Address = address.parse()?;
+    if matches!(address, Address::P2sh { .. }) {
+        return Err(Error::UnsupportedAddress);
+    }
+    let bytes = BASE64.decode(signature).map_err(|_| Error::InvalidBase64)?;
+    let witness = parse_witness(&bytes)?;
+    let to_spend = to_spend(&address.script_pubkey(), message.as_bytes());
+    let mut to_sign = to_sign(&to_spend);
+    to_sign.inputs[0].witness = witness;
+    verify_to_sign(&address, &to_spend, &to_sign)
+}
+
+/// Verifies a BIP322 full signature (a Base64 to_sign transaction) for a P2WPKH, P2WSH, P2TR
+/// or P2SH-wrapped segwit address.
+/// Returns `Ok(false)` if the transaction is not a valid to_sign for the message or its input
+/// does not prove control of the address. Returns the same errors as `verify_simple`, with
+/// `Error::InvalidTransaction` if the signature is not a transaction.
+pub fn verify_full(address: &str, signature: &str, message: &str) -> Result<bool, Error> {
+    let address: Address = address.parse()?;
+    let bytes = BASE64.decode(signature).map_err(|_| Error::InvalidBase64)?;
+    let to_sign = Transaction::parse(&bytes)?;
+    let to_spend = to_spend(&address.script_pubkey(), message.as_bytes());
+
+    // Only the version, sequence and lock time may differ from the template, for time locks
+    let expected = to_sign_template(&to_spend, &to_sign);
+    if (to_sign.version != 0 && to_sign.version != 2) || to_sign != expected {
+        return Ok(false);
+    }
+    verify_to_sign(&address, &to_spend, &to_sign)
+}
+
+/// Returns the unsigned to_sign with the version, lock time, sequence, scriptSig and witness
+/// taken from `signed`, so that comparing the two checks everything else.
+fn to_sign_template(to_spend: &Transaction, signed: &Transaction) -> Transaction {
+    let mut template = to_sign(to_spend);
+    template.version = signed.version;
+    template.lock_time = signed.lock_time;
+    if let Some(input) = signed.inputs.first() {
+        template.inputs[0].sequence = input.sequence;
+        template.inputs[0].script_sig = input.script_sig.clone();
+        template.inputs[0].witness = input.witness.clone();
+    }
+    template
+}
+
+/// Checks that the input of to_sign satisfies the address's script.
+fn verify_to_sign(
+    address: &Address,
+    to_spend: &Transaction,
+    to_sign: &Transaction,
+) -> Result<bool, Error> {
+    let input = &to_sign.inputs[0];
+    let script_pubkey = &to_spend.outputs[0].script_pubkey;
+    let program = match address {
+        Address::P2wpkh { .. } | Address::P2wsh { .. } => {
+            if !input.script_sig.is_empty() {
+                return Ok(false);
+            }
+            script_pubkey.as_slice()
+        }
+        Address::P2sh { script_hash, .. } => {
+            // The scriptSig must be a single push of the redeem script
+            let redeem_script = match script::instructions(&input.script_sig).as_deref() {
+                Some([Ok(redeem_script)]) => *redeem_script,
+                _ => return Ok(false),
+            };
+            if hash160(redeem_script) != *script_hash {
+                return Ok(false);
+            }
+            redeem_script
+        }
+        Address::P2tr { output_key, .. } => {
+            return Ok(input.script_sig.is_empty()
+                && verify_taproot_key_spend(output_key, &input.witness, to_spend, to_sign));
+        }
+        Address::P2pkh { .. } => return Err(Error::UnsupportedAddress),
+    };
+    let program = match script::witness_program(program) {
+        Some((0, program)) => program,
+        _ => return Err(Error::UnsupportedAddress),
+    };
+    let sighash = |script_code: &[u8]| to_sign.segwit_v0_sighash(0, script_code, 0);
+    Ok(script::verify_witness_v0(program, &input.witness, &sighash))
+}
+
+/// Checks a taproot key-path witness: a single BIP340 signature by the output key, 64 bytes for
+/// SIGHASH_DEFAULT or 65 bytes ending in SIGHASH_ALL.
+fn verify_taproot_key_spend(
+    output_key: &[u8; 32],
+    witness: &[Vec<u8>],
+    to_spend: &Transaction,
+    to_sign: &Transaction,
+) -> bool {
+    let (signature, hash_type) = match witness {
+        [signature] if signature.len() == 64 => (&signature[..], SIGHASH_DEFAULT),
+        [signature] if signature.len() == 65 && signature[64] == SIGHASH_ALL => {
+            (&signature[..64], SIGHASH_ALL)
+        }
+        _ => return false,
+    };
+    let (Ok(output_key), Ok(signature)) = (
+        XOnlyPublicKey::from_bytes(output_key),
+        SchnorrSignature::from_bytes(signature.try_into().unwrap()),
+    ) else {
+        return false;
+    };
+    let sighash = to_sign.taproot_key_spend_sighash(0, &to_spend.outputs, hash_type);
+    output_key.verify_schnorr(&sighash, &signature)
+}
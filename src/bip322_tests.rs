@@ -0,0 +1,388 @@
+use crate::address::{Address, Network};
+use crate::bip322::{message_hash, to_sign, to_spend, verify_full, verify_simple};
+use crate::error::Error;
+use crate::hash::hash160;
+use crate::keys::PrivateKey;
+use crate::scalar::Scalar;
+use crate::script::{OP_CHECKMULTISIG, OP_CHECKSIG, p2pkh_script, push_data};
+use crate::test_util::{bip322_key, key};
+use crate::transaction::{SIGHASH_ALL, SIGHASH_DEFAULT, TxOut, serialize_witness};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+// The P2WPKH address of the BIP322 test key
+const ADDRESS: &str = "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l";
+
+// The P2TR address of the BIP322 test key, and the official simple signature of "Hello World"
+// for it, which uses SIGHASH_ALL
+const P2TR_ADDRESS: &str = "bc1ppv609nr0vr25u07u95waq5lucwfm6tde4nydujnu8npg4q75mr5sxq8lt3";
+const P2TR_SIGNATURE: &str =
+    "AUHd69PrJQEv+oKTfZ8l+WROBHuy9HKrbFCJu7U1iK2iiEy1vMU5EfMtjc+VSHM7aU0SDbak5IUZRVno2P5mjSafAQ==";
+
+// BIP322 simple signatures of "" and "Hello World" for ADDRESS
+const EMPTY_SIGNATURE: &str = "AkcwRAIgM2gBAQqvZX15ZiysmKmQpDrG83avLIT492QBzLnQIxYCIBaTpOaD20qRlEylyxFSeEA2ba9YOixpX8z46TSDtS40ASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=";
+const HELLO_WORLD_SIGNATURE: &str = "AkcwRAIgZRfIY3p7/DoVTty6YZbWS71bc5Vct9p9Fia83eRmw2QCICK/ENGfwLtptFluMGs2KsqoNSk89pO7F29zJLUx9a/sASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=";
+
+// Helper function to encode a txid in the byte order explorers display
+fn display_txid(txid: [u8; 32]) -> String {
+    let mut txid = txid;
+    txid.reverse();
+    crate::hex::encode(&txid)
+}
+
+// Helper function to build a P2WSH proof: signs to_sign for `witness_script` with each key
+// and returns the Base64 witness <extra items> <signatures> <witness script>
+fn sign_p2wsh(
+    keys: &[PrivateKey],
+    extra: &[Vec<u8>],
+    witness_script: &[u8],
+    message: &str,
+) -> String {
+    let address = Address::p2wsh(witness_script, Network::Mainnet);
+    let to_spend = to_spend(&address.script_pubkey(), message.as_bytes());
+    let to_sign = to_sign(&to_spend);
+    let z = Scalar::reduce_be_bytes(&to_sign.segwit_v0_sighash(0, witness_script, 0));
+    let mut witness = extra.to_vec();
+    for key in keys {
+        let mut signature = key.sign_low_r(&z).to_der();
+        signature.push(SIGHASH_ALL);
+        witness.push(signature);
+    }
+    witness.push(witness_script.to_vec());
+    BASE64.encode(serialize_witness(&witness))
+}
+
+//------------------------------
+// Test Vector Tests
+//------------------------------
+
+#[test]
+fn test_message_hash_vectors() {
+    // Test the BIP322 tagged message hashes of "" and "Hello World".
+    assert_eq!(
+        crate::hex::encode(&message_hash(b"")),
+        "c90c269c4f8fcbe6880f72a721ddfbf1914268a794cbb21cfafee13770ae19f1"
+    );
+    assert_eq!(
+        crate::hex::encode(&message_hash(b"Hello World")),
+        "f0eb03b1a75ac6d9847f55c624a99169b5dccba2a31f5b23bea77ba270de0a7a"
+    );
+}
+
+#[test]
+fn test_virtual_transaction_vectors() {
+    // Test the BIP322 to_spend and to_sign txids for the test address.
+    let script_pubkey = ADDRESS.parse::<Address>().unwrap().script_pubkey();
+    let cases = [
+        (
+            "",
+            "c5680aa69bb8d860bf82d4e9cd3504b55dde018de765a91bb566283c545a99a7",
+            "1e9654e951a5ba44c8604c4de6c67fd78a27e81dcadcfe1edf638ba3aaebaed6",
+        ),
+        (
+            "Hello World",
+            "b79d196740ad5217771c1098fc4a4b51e0535c32236c71f1ea4d61a2d603352b",
+            "88737ae86f2077145f93cc4b153ae9a1cb8d56afa511988c149c5c8c9d93bddf",
+        ),
+    ];
+    for (message, to_spend_txid, to_sign_txid) in cases {
+        let to_spend = to_spend(&script_pubkey, message.as_bytes());
+        assert_eq!(display_txid(to_spend.txid()), to_spend_txid);
+        assert_eq!(display_txid(to_sign(&to_spend).txid()), to_sign_txid);
+    }
+}
+
+#[test]
+fn test_simple_signature_vectors() {
+    // Test that signing reproduces the BIP322 P2WPKH vectors, which use Core's low-R signatures,
+    // and that they verify only for their own message.
+    let address = ADDRESS.parse::<Address>().unwrap();
+    let key = bip322_key();
+    assert_eq!(
+        key.sign_bip322_simple(&address, "").unwrap(),
+        EMPTY_SIGNATURE
+    );
+    assert_eq!(
+        key.sign_bip322_simple(&address, "Hello World").unwrap(),
+        HELLO_WORLD_SIGNATURE
+    );
+    assert_eq!(verify_simple(ADDRESS, EMPTY_SIGNATURE, ""), Ok(true));
+    assert_eq!(
+        verify_simple(ADDRESS, HELLO_WORLD_SIGNATURE, "Hello World"),
+        Ok(true)
+    );
+    assert_eq!(verify_simple(ADDRESS, HELLO_WORLD_SIGNATURE, ""), Ok(false));
+    assert_eq!(
+        verify_simple(ADDRESS, EMPTY_SIGNATURE, "Hello World"),
+        Ok(false)
+    );
+}
+
+#[test]
+fn test_full_signature_vector() {
+    // Test the full format of the "Hello World" vector: the to_sign transaction carrying the
+    // simple signature as its witness.
+    let full = "AAAAAAABASs1A9aiYU3q8XFsIzJcU+BRS0r8mBAcdxdSrUBnGZ23AAAAAAAAAAAAAQAAAAAAAAAAAWoCRzBEAiBlF8hjenv8OhVO3LphltZLvVtzlVy32n0WJrzd5GbDZAIgIr8Q0Z/Au2m0WW4wazYqyqg1KTz2k7sXb3MktTH1r+wBIQLH8SADGWRClD2FiOAa7oQEI8xU/BUhUmo7hcKwy9WIcgAAAAA=";
+    let address = ADDRESS.parse::<Address>().unwrap();
+    assert_eq!(
+        bip322_key()
+            .sign_bip322_full(&address, "Hello World")
+            .unwrap(),
+        full
+    );
+    assert_eq!(verify_full(ADDRESS, full, "Hello World"), Ok(true));
+    assert_eq!(verify_full(ADDRESS, full, ""), Ok(false));
+}
+
+//------------------------------
+// Address Type Tests
+//------------------------------
+
+#[test]
+fn test_p2sh_p2wpkh_full_round_trip() {
+    // Test that P2SH-P2WPKH proofs use the full format and verify only for the signer's address.
+    let signer = bip322_key();
+    let address = Address::p2sh_p2wpkh(signer.public_key(), Network::Mainnet);
+    assert_eq!(address.to_string(), "37qyp7jQAzqb2rCBpMvVtLDuuzKAUCVnJb");
+    assert_eq!(
+        signer.sign_bip322_simple(&address, "Hello World"),
+        Err(Error::UnsupportedAddress)
+    );
+    assert_eq!(
+        verify_simple(&address.to_string(), HELLO_WORLD_SIGNATURE, "Hello World"),
+        Err(Error::UnsupportedAddress)
+    );
+    let full = signer.sign_bip322_full(&address, "Hello World").unwrap();
+    assert_eq!(
+        verify_full(&address.to_string(), &full, "Hello World"),
+        Ok(true)
+    );
+    assert_eq!(verify_full(&address.to_string(), &full, "Hello"), Ok(false));
+    let other = Address::p2sh_p2wpkh(key(&"01".repeat(32)).public_key(), Network::Mainnet);
+    assert_eq!(
+        verify_full(&other.to_string(), &full, "Hello World"),
+        Ok(false)
+    );
+}
+
+#[test]
+fn test_p2wsh_single_key() {
+    // Test a P2WSH proof for the witness script <key> OP_CHECKSIG, checked by the interpreter.
+    let key = bip322_key();
+    let mut witness_script = Vec::new();
+    push_data(&mut witness_script, &key.public_key().to_sec(true));
+    witness_script.push(OP_CHECKSIG);
+    let address = Address::p2wsh(&witness_script, Network::Mainnet).to_string();
+    let signature = sign_p2wsh(&[key], &[], &witness_script, "Hello World");
+    assert_eq!(verify_simple(&address, &signature, "Hello World"), Ok(true));
+    assert_eq!(verify_simple(&address, &signature, "Hello"), Ok(false));
+    assert_eq!(verify_simple(ADDRESS, &signature, "Hello World"), Ok(false));
+}
+
+#[test]
+fn test_p2wsh_multisig() {
+    // Test a 2-of-3 multisig P2WSH proof, which needs the signatures in key order.
+    let keys = [
+        key(&"11".repeat(32)),
+        key(&"22".repeat(32)),
+        key(&"33".repeat(32)),
+    ];
+    let mut witness_script = vec![0x52];
+    for key in &keys {
+        push_data(&mut witness_script, &key.public_key().to_sec(true));
+    }
+    witness_script.extend_from_slice(&[0x53, OP_CHECKMULTISIG]);
+    let address = Address::p2wsh(&witness_script, Network::Mainnet).to_string();
+    let [first, second, third] = keys;
+    let dummy = [Vec::new()];
+    let in_order = sign_p2wsh(
+        &[first.clone(), third.clone()],
+        &dummy,
+        &witness_script,
+        "msg",
+    );
+    assert_eq!(verify_simple(&address, &in_order, "msg"), Ok(true));
+    let out_of_order = sign_p2wsh(
+        &[third.clone(), first.clone()],
+        &dummy,
+        &witness_script,
+        "msg",
+    );
+    assert_eq!(verify_simple(&address, &out_of_order, "msg"), Ok(false));
+    let one_signature = sign_p2wsh(&[second], &dummy, &witness_script, "msg");
+    assert_eq!(verify_simple(&address, &one_signature, "msg"), Ok(false));
+    // The dummy element must be empty (NULLDUMMY)
+    let bad_dummy = sign_p2wsh(&[first, third], &[vec![1]], &witness_script, "msg");
+    assert_eq!(verify_simple(&address, &bad_dummy, "msg"), Ok(false));
+}
+
+#[test]
+fn test_p2tr_signature_vector() {
+    // Test the official BIP322 P2TR vector, a key-path signature by the tweaked key with
+    // SIGHASH_ALL, and that it verifies only for its own message and address.
+    assert_eq!(
+        verify_simple(P2TR_ADDRESS, P2TR_SIGNATURE, "Hello World"),
+        Ok(true)
+    );
+    assert_eq!(verify_simple(P2TR_ADDRESS, P2TR_SIGNATURE, ""), Ok(false));
+    let other = Address::p2tr(key(&"01".repeat(32)).public_key(), Network::Mainnet).unwrap();
+    assert_eq!(
+        verify_simple(&other.to_string(), P2TR_SIGNATURE, "Hello World"),
+        Ok(false)
+    );
+    // A SIGHASH_DEFAULT signature must not carry a hash type byte
+    let mut witness = BASE64.decode(P2TR_SIGNATURE).unwrap();
+    *witness.last_mut().unwrap() = SIGHASH_DEFAULT;
+    assert_eq!(
+        verify_simple(P2TR_ADDRESS, &BASE64.encode(witness), "Hello World"),
+        Ok(false)
+    );
+}
+
+#[test]
+fn test_p2tr_sighash() {
+    // Test the BIP341 key-path signature hashes of to_sign for the P2TR vector.
+    let script_pubkey = P2TR_ADDRESS.parse::<Address>().unwrap().script_pubkey();
+    let to_spend = to_spend(&script_pubkey, b"Hello World");
+    let to_sign = to_sign(&to_spend);
+    assert_eq!(
+        crate::hex::encode(&to_sign.taproot_key_spend_sighash(
+            0,
+            &to_spend.outputs,
+            SIGHASH_DEFAULT
+        )),
+        "f120ba1e18fea5877873de9f9c8a342ba570360d3b06e13ebdd390021a8985d0"
+    );
+    assert_eq!(
+        crate::hex::encode(&to_sign.taproot_key_spend_sighash(0, &to_spend.outputs, SIGHASH_ALL)),
+        "3c32825d6b1de7ec928033cbad24e7bfe51aeb16a493c76fbf59dd7b445ded22"
+    );
+}
+
+#[test]
+fn test_p2tr_round_trip() {
+    // Test that P2TR proofs are 64-byte SIGHASH_DEFAULT signatures, deterministic, and verify in
+    // both formats.
+    let signer = bip322_key();
+    let address = P2TR_ADDRESS.parse::<Address>().unwrap();
+    let simple = signer.sign_bip322_simple(&address, "Hello World").unwrap();
+    assert_eq!(
+        simple,
+        "AUDjpClYFHngjnqQ3F0/3dyrLsOHFNEm4rKaaAc9GsfhC5+DngPJmXTeAmz+yfsVRa61PD2k9/CEQnLDvNUn9Qug"
+    );
+    assert_eq!(
+        verify_simple(P2TR_ADDRESS, &simple, "Hello World"),
+        Ok(true)
+    );
+    assert_eq!(verify_simple(P2TR_ADDRESS, &simple, "Hello"), Ok(false));
+    let full = signer.sign_bip322_full(&address, "Hello World").unwrap();
+    assert_eq!(verify_full(P2TR_ADDRESS, &full, "Hello World"), Ok(true));
+    assert_eq!(
+        key(&"01".repeat(32)).sign_bip322_simple(&address, "Hello World"),
+        Err(Error::KeyMismatch)
+    );
+}
+
+#[test]
+fn test_unsupported_addresses() {
+    // Test that P2PKH proofs are reported as unsupported rather than invalid.
+    let key = bip322_key();
+    let p2pkh = Address::p2pkh(key.public_key(), true, Network::Mainnet);
+    assert_eq!(
+        key.sign_bip322_full(&p2pkh, "Hello World"),
+        Err(Error::UnsupportedAddress)
+    );
+    assert_eq!(
+        verify_simple(&p2pkh.to_string(), HELLO_WORLD_SIGNATURE, "Hello World"),
+        Err(Error::UnsupportedAddress)
+    );
+}
+
+#[test]
+fn test_sign_with_wrong_key() {
+    // Test that signing for an address the key does not control fails.
+    let address = ADDRESS.parse::<Address>().unwrap();
+    let other = key(&"01".repeat(32));
+    assert_eq!(
+        other.sign_bip322_simple(&address, ""),
+        Err(Error::KeyMismatch)
+    );
+}
+
+#[test]
+fn test_testnet_address() {
+    // Test that a testnet address, which has the same output script, gets the same proof.
+    let testnet = Address::p2wpkh(bip322_key().public_key(), Network::Testnet);
+    assert_eq!(
+        bip322_key().sign_bip322_simple(&testnet, "").unwrap(),
+        EMPTY_SIGNATURE
+    );
+    assert_eq!(
+        verify_simple(&testnet.to_string(), EMPTY_SIGNATURE, ""),
+        Ok(true)
+    );
+}
+
+//------------------------------
+// Malformed Proof Tests
+//------------------------------
+
+#[test]
+fn test_verify_malformed_proofs() {
+    // Test that undecodable proofs are errors, while well-formed wrong ones are invalid.
+    assert_eq!(
+        verify_simple(ADDRESS, "not base64!", ""),
+        Err(Error::InvalidBase64)
+    );
+    assert_eq!(
+        verify_simple(ADDRESS, "AkcwRA==", ""),
+        Err(Error::InvalidTransaction)
+    );
+    assert_eq!(
+        verify_full(ADDRESS, EMPTY_SIGNATURE, ""),
+        Err(Error::InvalidTransaction)
+    );
+    // An empty witness and a witness with only the key
+    assert_eq!(verify_simple(ADDRESS, "AA==", ""), Ok(false));
+    let key_only = serialize_witness(&[bip322_key().public_key().to_sec(true)]);
+    assert_eq!(
+        verify_simple(ADDRESS, &BASE64.encode(key_only), ""),
+        Ok(false)
+    );
+}
+
+#[test]
+fn test_verify_full_rejects_altered_to_sign() {
+    // Test that a full proof whose to_sign has an extra output or another version is invalid,
+    // even though its signature commits to that transaction.
+    let address = ADDRESS.parse::<Address>().unwrap();
+    let full = bip322_key()
+        .sign_bip322_full(&address, "Hello World")
+        .unwrap();
+    let mut bytes = BASE64.decode(full).unwrap();
+    // Version 1 instead of 0
+    bytes[0] = 1;
+    assert_eq!(
+        verify_full(ADDRESS, &BASE64.encode(&bytes), "Hello World"),
+        Ok(false)
+    );
+
+    // A second output, with the input signed over the altered transaction
+    let key = bip322_key();
+    let public_key = key.public_key().to_sec(true);
+    let to_spend = to_spend(&address.script_pubkey(), b"Hello World");
+    let mut altered = to_sign(&to_spend);
+    altered.outputs.push(TxOut {
+        value: 1000,
+        script_pubkey: address.script_pubkey(),
+    });
+    let script_code = p2pkh_script(&hash160(&public_key));
+    let z = Scalar::reduce_be_bytes(&altered.segwit_v0_sighash(0, &script_code, 0));
+    let mut signature = key.sign_low_r(&z).to_der();
+    signature.push(SIGHASH_ALL);
+    altered.inputs[0].witness = vec![signature, public_key];
+    assert_eq!(
+        verify_full(ADDRESS, &BASE64.encode(altered.serialize()), "Hello World"),
+        Ok(false)
+    );
+}
@@ -0,0 +1,215 @@
+use crate::finite_fields::FieldElement;
+use crate::point::{G, Point};
+use crate::scalar::Scalar;
+use num_bigint::BigInt;
+use num_traits::One;
+use proptest::prelude::*;
+use proptest::test_runner::{RngAlgorithm, TestRng};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use subtle::{Choice, ConstantTimeEq};
+
+// Helper function drawing a uniformly random scalar from the harness RNG
+fn random_scalar(rng: &mut TestRng) -> Scalar {
+    let mut bytes = [0u8; 32];
+    rng.fill_bytes(&mut bytes);
+    Scalar::reduce_be_bytes(&bytes)
+}
+
+//--------------------------
+// Constant-Time Primitives
+//--------------------------
+
+#[test]
+fn test_ct_eq() {
+    // Test that constant-time equality agrees with ==.
+    let a = FieldElement::new(BigInt::from(5)).unwrap();
+    let b = FieldElement::new(BigInt::from(6)).unwrap();
+    assert!(bool::from(a.ct_eq(&a.clone())));
+    assert!(!bool::from(a.ct_eq(&b)));
+}
+
+#[test]
+fn test_conditional_select() {
+    // Test that conditional_select returns the first operand for 0 and the second for 1.
+    let a = FieldElement::new(BigInt::from(5)).unwrap();
+    let b = FieldElement::new(BigInt::from(6)).unwrap();
+    assert_eq!(FieldElement::conditional_select(&a, &b, Choice::from(0)), a);
+    assert_eq!(FieldElement::conditional_select(&a, &b, Choice::from(1)), b);
+}
+
+#[test]
+fn test_conditional_swap() {
+    // Test that conditional_swap only swaps when the choice is set.
+    let a = FieldElement::new(BigInt::from(5)).unwrap();
+    let b = FieldElement::new(BigInt::from(6)).unwrap();
+    let (mut x, mut y) = (a.clone(), b.clone());
+    FieldElement::conditional_swap(&mut x, &mut y, Choice::from(0));
+    assert_eq!((&x, &y), (&a, &b));
+    FieldElement::conditional_swap(&mut x, &mut y, Choice::from(1));
+    assert_eq!((&x, &y), (&b, &a));
+}
+
+//---------------------------------
+// Constant-Time Ladder Tests
+//---------------------------------
+
+#[test]
+fn test_mul_ct_small_scalars() {
+    // Test that the ladder agrees with the variable-time `&G * &k` (the windowed method, or GLV
+    // with the glv feature) for 0, 1, 2 and 3.
+    for k in 0..4u64 {
+        let k = Scalar::new(BigInt::from(k)).unwrap();
+        assert_eq!(G.mul_ct(&k), &G * &k);
+    }
+}
+
+#[test]
+fn test_mul_ct_n_minus_one() {
+    // Test that (N - 1) * G = -G, exercising the top bits of the ladder.
+    let k = Scalar::new(Scalar::prime() - BigInt::one()).unwrap();
+    let neg_g = Point::new(Some(G.x().clone()), Some(-G.y())).unwrap();
+    assert_eq!(G.mul_ct(&k), neg_g);
+}
+
+#[test]
+fn test_mul_ct_infinity() {
+    // Test that multiplying the point at infinity yields infinity.
+    let k = Scalar::new(BigInt::from(42)).unwrap();
+    assert_eq!(Point::Infinity.mul_ct(&k), Point::Infinity);
+}
+
+//----------------------------------------------------------------------
+// Timing Leakage Harness (dudect-style)
+//
+// Run locally with: cargo test --release -- --ignored dudect --nocapture
+// Each test times an operation on a "fixed" input class and a "random" input
+// class in random interleaved order and computes Welch's t-statistic. A value
+// of |t| above 10 is the conventional dudect threshold for a definite leak.
+//----------------------------------------------------------------------
+
+const DUDECT_SAMPLES: usize = 4000;
+const DUDECT_THRESHOLD: f64 = 10.0;
+
+// Welch's t-statistic for two independent samples
+fn welch_t(a: &[f64], b: &[f64]) -> f64 {
+    let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+    let var =
+        |v: &[f64], m: f64| v.iter().map(|x| (x - m).powi(2)).sum::<f64>() / (v.len() - 1) as f64;
+    let (ma, mb) = (mean(a), mean(b));
+    let (va, vb) = (var(a, ma), var(b, mb));
+    (ma - mb) / (va / a.len() as f64 + vb / b.len() as f64).sqrt()
+}
+
+// Times `operation` on inputs drawn from two classes and returns Welch's t-statistic.
+// Measurements above the 90th percentile are cropped to suppress interrupts and scheduling noise.
+fn dudect_t_statistic<T>(
+    fixed: T,
+    random: impl Fn(&mut TestRng) -> T,
+    operation: impl Fn(&T),
+) -> f64 {
+    let mut seed = [0u8; 32];
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    seed[..16].copy_from_slice(&now.as_nanos().to_le_bytes());
+    let mut rng = TestRng::from_seed(RngAlgorithm::ChaCha, &seed);
+    let inputs: Vec<(bool, T)> = (0..DUDECT_SAMPLES)
+        .map(|_| (rng.next_u32() & 1 == 1, random(&mut rng)))
+        .collect();
+
+    let mut timings = Vec::with_capacity(inputs.len());
+    for (is_fixed, input) in &inputs {
+        let input = if *is_fixed { &fixed } else { input };
+        let start = Instant::now();
+        operation(input);
+        timings.push((*is_fixed, start.elapsed().as_nanos() as f64));
+    }
+
+    let mut sorted: Vec<f64> = timings.iter().map(|(_, t)| *t).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let cutoff = sorted[sorted.len() * 9 / 10];
+    let class = |fixed: bool| -> Vec<f64> {
+        timings
+            .iter()
+            .filter(|(is_fixed, t)| *is_fixed == fixed && *t <= cutoff)
+            .map(|(_, t)| *t)
+            .collect()
+    };
+    welch_t(&class(true), &class(false))
+}
+
+#[test]
+#[ignore]
+fn test_dudect_mul_ct() {
+    // Test that the constant-time ladder shows no gross timing difference between k = 1 and random k.
+    let t = dudect_t_statistic(Scalar::one(), random_scalar, |k| {
+        std::hint::black_box(G.mul_ct(std::hint::black_box(k)));
+    });
+    println!("mul_ct: t = {:.2}", t);
+    assert!(
+        t.abs() < DUDECT_THRESHOLD,
+        "timing leak detected: t = {}",
+        t
+    );
+}
+
+#[test]
+#[ignore]
+fn test_dudect_mul_generator() {
+    // Test that the table-based k * G shows no gross timing difference between k = 1 and random k.
+    let t = dudect_t_statistic(Scalar::one(), random_scalar, |k| {
+        std::hint::black_box(Point::mul_generator(std::hint::black_box(k)));
+    });
+    println!("mul_generator: t = {:.2}", t);
+    assert!(
+        t.abs() < DUDECT_THRESHOLD,
+        "timing leak detected: t = {}",
+        t
+    );
+}
+
+#[test]
+#[ignore]
+fn test_dudect_field_inverse() {
+    // Test that field inversion shows no gross timing difference between 1 and random elements.
+    let random_element = |rng: &mut TestRng| {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        FieldElement::reduce_be_bytes(&bytes)
+    };
+    let t = dudect_t_statistic(FieldElement::one(), random_element, |a| {
+        std::hint::black_box(std::hint::black_box(a).inverse());
+    });
+    println!("inverse: t = {:.2}", t);
+    assert!(
+        t.abs() < DUDECT_THRESHOLD,
+        "timing leak detected: t = {}",
+        t
+    );
+}
+
+#[test]
+#[ignore]
+fn test_dudect_detects_variable_time_mul() {
+    // Test that the harness is sensitive: the variable-time `&G * &k` (windowed, or GLV with the
+    // glv feature) is much faster for k = 1 than for random k.
+    let t = dudect_t_statistic(Scalar::one(), random_scalar, |k| {
+        std::hint::black_box(&G * std::hint::black_box(k));
+    });
+    println!("variable-time mul: t = {:.2}", t);
+    assert!(
+        t.abs() > DUDECT_THRESHOLD,
+        "harness failed to detect leak: t = {}",
+        t
+    );
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn prop_mul_ct_matches_variable_time_mul(k in any::<[u8; 32]>()) {
+        // Test that the ladder agrees with the variable-time `&G * &k` (the windowed method, or
+        // GLV with the glv feature) on random scalars.
+        let k = Scalar::reduce_be_bytes(&k);
+        prop_assert_eq!(G.mul_ct(&k), &G * &k);
+    }
+}
@@ -0,0 +1,277 @@
+// Short-Weierstrass curves y^2 = x^3 + ax + b over a prime field, and points on them.
+// The arithmetic here is the textbook chord-and-tangent construction and works for any a and b.
+// A curve can override `Curve::add` and `Curve::mul` with faster formulas; secp256k1 does so
+// with Jacobian coordinates, windowed multiplication and the GLV endomorphism (see point.rs).
+
+use crate::error::Error;
+use crate::finite_fields::{Fp, PrimeField};
+use core::fmt;
+use core::ops::{Add, Mul};
+use core::str::FromStr;
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+
+/// Describes a short-Weierstrass curve y^2 = x^3 + ax + b together with a generator of prime order n.
+/// Implemented by zero-sized marker types, so points on different curves are distinct types.
+pub trait Curve: Clone + fmt::Debug + PartialEq + Eq + Sized {
+    /// Human-readable name of the curve, e.g. "secp256k1".
+    const NAME: &'static str;
+
+    /// Cofactor h = #E / n, the number of curve points divided by the order of the generator.
+    const COFACTOR: u64;
+
+    /// The base field the coordinates live in.
+    type Field: PrimeField;
+
+    /// The scalar field: integers modulo the prime order n of the generator.
+    type Order: PrimeField;
+
+    /// Returns the coefficient a of the curve equation.
+    fn a() -> Fp<Self::Field>;
+
+    /// Returns the coefficient b of the curve equation.
+    fn b() -> Fp<Self::Field>;
+
+    /// Returns the generator G of the prime-order subgroup.
+    fn generator() -> CurvePoint<Self>;
+
+    /// Adds two points. Curves with faster formulas override this.
+    fn add(p: &CurvePoint<Self>, q: &CurvePoint<Self>) -> CurvePoint<Self> {
+        affine_add(p, q)
+    }
+
+    /// Computes k * P for a point in the prime-order subgroup. Curves with faster
+    /// algorithms override this; the default is binary double-and-add.
+    fn mul(point: &CurvePoint<Self>, k: &Fp<Self::Order>) -> CurvePoint<Self> {
+        double_and_add(point, &k.to_bigint())
+    }
+}
+
+/// Represents a point on the curve `C`: either the point at infinity or affine coordinates (x, y).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CurvePoint<C: Curve> {
+    Infinity,
+    Coordinates { x: Fp<C::Field>, y: Fp<C::Field> },
+}
+
+impl<C: Curve> CurvePoint<C> {
+    /// Constructs a new point on the curve `C`: y^2 = x^3 + ax + b.
+    /// If both x and y are None, returns the point at infinity.
+    /// If both are Some, validates the curve equation and returns `Error::NotOnCurve` if it fails.
+    pub fn new(x: Option<Fp<C::Field>>, y: Option<Fp<C::Field>>) -> Result<Self, Error> {
+        match (x, y) {
+            // Point at infinity
+            (None, None) => Ok(CurvePoint::Infinity),
+
+            // Regular point with both coordinates
+            (Some(x), Some(y)) => {
+                if is_on_curve::<C>(&x, &y) {
+                    Ok(CurvePoint::Coordinates { x, y })
+                } else {
+                    Err(Error::NotOnCurve)
+                }
+            }
+            _ => Err(Error::MismatchedCoordinates),
+        }
+    }
+
+    /// Returns the generator of the curve's prime-order subgroup.
+    pub fn generator() -> Self {
+        C::generator()
+    }
+}
+
+/// Returns true if (x, y) satisfies y^2 = x^3 + ax + b.
+fn is_on_curve<C: Curve>(x: &Fp<C::Field>, y: &Fp<C::Field>) -> bool {
+    y.square() == curve_rhs::<C>(x)
+}
+
+/// Evaluates the right-hand side x^3 + ax + b of the curve equation.
+pub(crate) fn curve_rhs<C: Curve>(x: &Fp<C::Field>) -> Fp<C::Field> {
+    &(&(&x.square() * x) + &(&C::a() * x)) + &C::b()
+}
+
+/// Adds two points with the affine chord-and-tangent formulas, handling infinity,
+/// P + (-P) and doubling (including points with y = 0).
+pub(crate) fn affine_add<C: Curve>(p: &CurvePoint<C>, q: &CurvePoint<C>) -> CurvePoint<C> {
+    let (x1, y1, x2, y2) = match (p, q) {
+        (CurvePoint::Infinity, _) => return q.clone(),
+        (_, CurvePoint::Infinity) => return p.clone(),
+        (CurvePoint::Coordinates { x: x1, y: y1 }, CurvePoint::Coordinates { x: x2, y: y2 }) => {
+            (x1, y1, x2, y2)
+        }
+    };
+
+    let slope = if x1 != x2 {
+        // Chord through two distinct points: s = (y2 - y1) / (x2 - x1)
+        &(y2 - y1) / &(x2 - x1)
+    } else if y1 == y2 && !y1.is_zero() {
+        // Tangent at P: s = (3x^2 + a) / 2y
+        let x_squared = x1.square();
+        let numerator = &(&(&x_squared + &x_squared) + &x_squared) + &C::a();
+        &numerator / &(y1 + y1)
+    } else {
+        // P + (-P), including the vertical tangent at y = 0
+        return CurvePoint::Infinity;
+    };
+
+    let x3 = &(&slope.square() - x1) - x2;
+    let y3 = &(&slope * &(x1 - &x3)) - y1;
+    CurvePoint::Coordinates { x: x3, y: y3 }
+}
+
+/// Computes k * P for a non-negative integer k by scanning its bits from the top.
+/// Runs in variable time and goes through `Curve::add`.
+pub(crate) fn double_and_add<C: Curve>(point: &CurvePoint<C>, k: &BigInt) -> CurvePoint<C> {
+    let mut result = CurvePoint::Infinity;
+    for i in (0..k.bits()).rev() {
+        result = C::add(&result, &result);
+        if k.bit(i) {
+            result = C::add(&result, point);
+        }
+    }
+    result
+}
+
+/// Formats a `CurvePoint` as a string for display purposes.
+impl<C: Curve> fmt::Display for CurvePoint<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurvePoint::Infinity => write!(f, "Point(Infinity)"),
+            CurvePoint::Coordinates { x, y } => {
+                write!(f, "Point(x=0x{:064x}, y=0x{:064x})", x.num(), y.num())
+            }
+        }
+    }
+}
+
+/// Parses the format produced by `Display`: `Point(Infinity)` or `Point(x=0x..., y=0x...)`.
+/// Returns `Error::NotOnCurve` if the coordinates do not satisfy the curve equation.
+impl<C: Curve> FromStr for CurvePoint<C> {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if s == "Point(Infinity)" {
+            return Ok(CurvePoint::Infinity);
+        }
+        let (x, y) = s
+            .strip_prefix("Point(x=0x")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .and_then(|rest| rest.split_once(", y=0x"))
+            .ok_or(Error::InvalidFormat)?;
+        CurvePoint::new(Some(Fp::from_hex(x)?), Some(Fp::from_hex(y)?))
+    }
+}
+
+/// Implement Add for references to CurvePoint
+/// Delegates to `Curve::add`, so curves with specialized formulas use them.
+impl<'a, C: Curve> Add<&'a CurvePoint<C>> for &CurvePoint<C> {
+    type Output = CurvePoint<C>;
+    fn add(self, rhs: &'a CurvePoint<C>) -> CurvePoint<C> {
+        C::add(self, rhs)
+    }
+}
+
+/// Implement Add for owned CurvePoint values
+impl<C: Curve> Add for CurvePoint<C> {
+    type Output = CurvePoint<C>;
+    fn add(self, rhs: CurvePoint<C>) -> CurvePoint<C> {
+        &self + &rhs
+    }
+}
+
+/// Implement Mul for references to CurvePoint and scalars modulo the group order
+/// Delegates to `Curve::mul`. Runs in variable time depending on the bits of the scalar.
+impl<C: Curve> Mul<&Fp<C::Order>> for &CurvePoint<C> {
+    type Output = CurvePoint<C>;
+    fn mul(self, rhs: &Fp<C::Order>) -> CurvePoint<C> {
+        C::mul(self, rhs)
+    }
+}
+
+/// Implement Mul for owned CurvePoint and scalar
+impl<C: Curve> Mul<Fp<C::Order>> for CurvePoint<C> {
+    type Output = CurvePoint<C>;
+
+    fn mul(self, rhs: Fp<C::Order>) -> CurvePoint<C> {
+        &self * &rhs
+    }
+}
+
+/// Implement Mul for references to CurvePoint and BigInt
+/// The integer is reduced modulo N (negative values included) before multiplying. On curves with
+/// a cofactor it is reduced modulo h * N instead, which is valid for every point on the curve.
+impl<C: Curve> Mul<&BigInt> for &CurvePoint<C> {
+    type Output = CurvePoint<C>;
+    fn mul(self, rhs: &BigInt) -> CurvePoint<C> {
+        if C::COFACTOR == 1 {
+            return self * &Fp::<C::Order>::reduce(rhs);
+        }
+        let group_order = C::Order::modulus() * C::COFACTOR;
+        let mut k = rhs % &group_order;
+        if k.is_negative() {
+            k += &group_order;
+        }
+        if k.is_zero() {
+            return CurvePoint::Infinity;
+        }
+        double_and_add(self, &k)
+    }
+}
+
+/// Implement Mul for owned CurvePoint and BigInt
+impl<C: Curve> Mul<BigInt> for CurvePoint<C> {
+    type Output = CurvePoint<C>;
+
+    fn mul(self, rhs: BigInt) -> CurvePoint<C> {
+        // Delegate to the reference version
+        &self * &rhs
+    }
+}
+
+//--------------------------
+// secp256r1 (NIST P-256)
+//--------------------------
+
+crate::prime_field!(
+    /// Marker type for the secp256r1 base field, p = 2^256 - 2^224 + 2^192 + 2^96 - 1.
+    pub P256Field,
+    "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+);
+
+crate::prime_field!(
+    /// Marker type for the secp256r1 scalar field: integers modulo the group order n.
+    pub P256Order,
+    "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+);
+
+/// The NIST P-256 curve y^2 = x^3 - 3x + b, for interoperability testing.
+/// Uses the generic affine arithmetic and generic modular reduction, so it is much slower than secp256k1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Secp256r1;
+
+impl Curve for Secp256r1 {
+    const NAME: &'static str = "secp256r1";
+    const COFACTOR: u64 = 1;
+    type Field = P256Field;
+    type Order = P256Order;
+
+    fn a() -> Fp<P256Field> {
+        -Fp::new(BigInt::from(3)).unwrap()
+    }
+
+    fn b() -> Fp<P256Field> {
+        hex_element("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b")
+    }
+
+    fn generator() -> CurvePoint<Self> {
+        CurvePoint::Coordinates {
+            x: hex_element("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"),
+            y: hex_element("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"),
+        }
+    }
+}
+
+// Helper function to build a field element from a hex constant
+fn hex_element<F: PrimeField>(hex: &str) -> Fp<F> {
+    Fp::new(BigInt::parse_bytes(hex.as_bytes(), 16).unwrap()).unwrap()
+}
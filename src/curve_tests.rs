@@ -0,0 +1,190 @@
+use crate::curve::{Curve, CurvePoint, Secp256r1, affine_add, double_and_add};
+use crate::error::Error;
+use crate::finite_fields::Fp;
+use crate::point::{G, Point, Secp256k1};
+use crate::scalar::Scalar;
+use num_bigint::BigInt;
+
+//----------------------------------------
+// Toy curve y^2 = x^3 + 7 over F_223
+//----------------------------------------
+
+crate::prime_field!(F223, "df");
+crate::prime_field!(F7, "7");
+
+// The curve from the book's chapter 3 exercises. It has 252 = 36 * 7 points,
+// and (15, 86) generates the subgroup of order 7.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Toy223;
+
+impl Curve for Toy223 {
+    const NAME: &'static str = "toy F_223";
+    const COFACTOR: u64 = 36;
+    type Field = F223;
+    type Order = F7;
+
+    fn a() -> Fp<F223> {
+        Fp::zero()
+    }
+
+    fn b() -> Fp<F223> {
+        Fp::new(BigInt::from(7)).unwrap()
+    }
+
+    fn generator() -> CurvePoint<Self> {
+        toy_point(15, 86)
+    }
+}
+
+// Helper function to create a point on the toy curve from small coordinates
+fn toy_point(x: u64, y: u64) -> CurvePoint<Toy223> {
+    CurvePoint::new(
+        Some(Fp::new(BigInt::from(x)).unwrap()),
+        Some(Fp::new(BigInt::from(y)).unwrap()),
+    )
+    .unwrap()
+}
+
+// Helper function to parse a hex constant into an element of any field
+fn hex<F: crate::finite_fields::PrimeField>(hex: &str) -> Fp<F> {
+    Fp::new(BigInt::parse_bytes(hex.as_bytes(), 16).unwrap()).unwrap()
+}
+
+#[test]
+fn test_toy_curve_membership() {
+    // Test the book's exercise: which points lie on y^2 = x^3 + 7 over F_223.
+    let on_curve = [(192, 105), (17, 56), (1, 193)];
+    let off_curve = [(200, 119), (42, 99)];
+    for (x, y) in on_curve {
+        toy_point(x, y);
+    }
+    for (x, y) in off_curve {
+        let result = CurvePoint::<Toy223>::new(
+            Some(Fp::new(BigInt::from(x)).unwrap()),
+            Some(Fp::new(BigInt::from(y)).unwrap()),
+        );
+        assert_eq!(result.unwrap_err(), Error::NotOnCurve);
+    }
+}
+
+#[test]
+fn test_toy_curve_addition() {
+    // Test the book's addition exercises on the F_223 curve.
+    let cases = [
+        ((170, 142), (60, 139), (220, 181)),
+        ((47, 71), (17, 56), (215, 68)),
+        ((143, 98), (76, 66), (47, 71)),
+    ];
+    for (p, q, sum) in cases {
+        assert_eq!(
+            &toy_point(p.0, p.1) + &toy_point(q.0, q.1),
+            toy_point(sum.0, sum.1)
+        );
+    }
+}
+
+#[test]
+fn test_toy_curve_scalar_multiplication() {
+    // Test the book's scalar multiplication exercises, including a point outside the generator's subgroup.
+    assert_eq!(&toy_point(192, 105) * &BigInt::from(2), toy_point(49, 71));
+    assert_eq!(&toy_point(143, 98) * &BigInt::from(2), toy_point(64, 168));
+    assert_eq!(&toy_point(47, 71) * &BigInt::from(2), toy_point(36, 111));
+    assert_eq!(&toy_point(47, 71) * &BigInt::from(4), toy_point(194, 51));
+    assert_eq!(&toy_point(47, 71) * &BigInt::from(8), toy_point(116, 55));
+    assert_eq!(&toy_point(47, 71) * &BigInt::from(21), CurvePoint::Infinity);
+    assert_eq!(&toy_point(47, 71) * &BigInt::from(-1), toy_point(47, 152));
+}
+
+#[test]
+fn test_toy_curve_generator_order() {
+    // Test that the generator (15, 86) has order 7, with scalars taken modulo 7.
+    let g = CurvePoint::<Toy223>::generator();
+    assert_eq!(&g * &Fp::<F7>::zero(), CurvePoint::Infinity);
+    assert_eq!(&g * &BigInt::from(7), CurvePoint::Infinity);
+    for k in 1..7u64 {
+        let k = Fp::<F7>::new(BigInt::from(k)).unwrap();
+        assert_ne!(&g * &k, CurvePoint::Infinity);
+    }
+    let six = Fp::<F7>::new(BigInt::from(6)).unwrap();
+    assert_eq!(&g * &six, toy_point(15, 137));
+}
+
+//-------------------------
+// secp256r1 (NIST P-256)
+//-------------------------
+
+// Helper function to build a P-256 point from hex coordinates
+fn p256_point(x: &str, y: &str) -> CurvePoint<Secp256r1> {
+    CurvePoint::new(Some(hex(x)), Some(hex(y))).unwrap()
+}
+
+#[test]
+fn test_p256_generator_is_on_curve() {
+    // Test that the P-256 generator satisfies the curve equation with a = -3.
+    let g = Secp256r1::generator();
+    if let CurvePoint::Coordinates { x, y } = &g {
+        assert!(CurvePoint::<Secp256r1>::new(Some(x.clone()), Some(y.clone())).is_ok());
+    } else {
+        panic!("generator must not be the point at infinity");
+    }
+}
+
+#[test]
+fn test_p256_known_multiples() {
+    // Test 2G, 3G and k * G against the published P-256 test vectors.
+    let g = Secp256r1::generator();
+    let two_g = p256_point(
+        "7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+        "07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1",
+    );
+    let three_g = p256_point(
+        "5ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c",
+        "8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032",
+    );
+    assert_eq!(&g + &g, two_g);
+    assert_eq!(&two_g + &g, three_g);
+    assert_eq!(&g * &BigInt::from(3), three_g);
+
+    let k = BigInt::from(112233445566778899u64);
+    let expected = p256_point(
+        "339150844ec15234807fe862a86be77977dbfb3ae3d96f4c22795513aeaab82f",
+        "b1c14ddfdc8ec1b2583f51e85a5eb3a155840f2034730e9b5ada38b674336a21",
+    );
+    assert_eq!(&g * &k, expected);
+}
+
+#[test]
+fn test_p256_scalars_reduce_modulo_order() {
+    // Test that integer multipliers are reduced modulo n, and that G + (-G) is the point at infinity.
+    let g = Secp256r1::generator();
+    let n = Fp::<crate::curve::P256Order>::prime();
+    assert_eq!(&g * &(n + BigInt::from(2)), &g + &g);
+    assert_eq!(&g * n, CurvePoint::Infinity);
+    if let CurvePoint::Coordinates { x, y } = &g {
+        let minus_g = CurvePoint::<Secp256r1>::new(Some(x.clone()), Some(-y)).unwrap();
+        assert_eq!(&g + &minus_g, CurvePoint::Infinity);
+    }
+}
+
+//----------------------------------
+// secp256k1 Specialization Checks
+//----------------------------------
+
+#[test]
+fn test_secp256k1_fast_paths_match_generic() {
+    // Test that the Jacobian addition and windowed multiplication agree with the generic affine code.
+    let k =
+        Scalar::new(BigInt::parse_bytes(b"aa5e28d6a97a2479a65527f7290311a3", 16).unwrap()).unwrap();
+    let p = Point::mul_generator(&k);
+    assert_eq!(affine_add(&p, &G), &p + &G);
+    assert_eq!(affine_add(&p, &p), &p + &p);
+    assert_eq!(double_and_add::<Secp256k1>(&G, k.num()), &G * &k);
+}
+
+#[test]
+fn test_secp256k1_generator() {
+    // Test that the curve trait exposes the same generator and coefficients as the constants.
+    assert_eq!(Point::generator(), G);
+    assert!(Secp256k1::a().is_zero());
+    assert_eq!(Secp256k1::b(), Fp::new(BigInt::from(7)).unwrap());
+}
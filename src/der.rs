@@ -0,0 +1,168 @@
+// DER encoding of ECDSA signatures, as carried in Bitcoin transactions:
+// 0x30 <total length> 0x02 <length of r> <r> 0x02 <length of s> <s>
+// Each integer is big-endian, minimal, and positive: a leading 0x00 is added only when the top
+// bit of the first byte is set. Lengths always fit in one byte, since the whole is at most 72 bytes.
+// BIP66 made this strict form a consensus rule; older transactions contain looser BER encodings,
+// which `from_der_lax` accepts the same way Bitcoin Core does.
+
+use crate::ecdsa::Signature;
+use crate::error::Error;
+use crate::scalar::Scalar;
+use alloc::vec::Vec;
+
+impl Signature {
+    /// Encodes the signature in strict DER (70 to 72 bytes for typical signatures).
+    pub fn to_der(&self) -> Vec<u8> {
+        let r = der_integer(&self.r().to_be_bytes());
+        let s = der_integer(&self.s().to_be_bytes());
+        let mut bytes = Vec::with_capacity(6 + r.len() + s.len());
+        bytes.extend_from_slice(&[0x30, (4 + r.len() + s.len()) as u8]);
+        bytes.extend_from_slice(&[0x02, r.len() as u8]);
+        bytes.extend_from_slice(&r);
+        bytes.extend_from_slice(&[0x02, s.len() as u8]);
+        bytes.extend_from_slice(&s);
+        bytes
+    }
+
+    /// Parses a strict DER signature following the BIP66 rules (without the trailing sighash byte):
+    /// exact lengths, no negative integers and no unnecessary leading zeros.
+    /// Returns `Error::InvalidDer` for any other encoding, and `Error::OutOfRange` if r or s is
+    /// zero or not below N.
+    pub fn from_der(bytes: &[u8]) -> Result<Self, Error> {
+        let len = bytes.len();
+        if !(8..=72).contains(&len) || bytes[0] != 0x30 || bytes[1] as usize != len - 2 {
+            return Err(Error::InvalidDer);
+        }
+        let r_len = bytes[3] as usize;
+        if 5 + r_len >= len {
+            return Err(Error::InvalidDer);
+        }
+        let s_len = bytes[5 + r_len] as usize;
+        if r_len + s_len + 6 != len {
+            return Err(Error::InvalidDer);
+        }
+        let r = strict_integer(&bytes[2..4 + r_len])?;
+        let s = strict_integer(&bytes[4 + r_len..])?;
+        Signature::new(scalar_from_integer(r)?, scalar_from_integer(s)?)
+    }
+
+    /// Parses a DER-like signature as leniently as Bitcoin Core's `ecdsa_signature_parse_der_lax`,
+    /// for signatures that predate BIP66: the sequence length is ignored, lengths may use the long
+    /// form, integers may be padded with zeros, and trailing bytes are allowed.
+    /// Bitcoin Core turns an r or s that does not fit below N into an unverifiable signature;
+    /// here that is `Error::OutOfRange`.
+    pub fn from_der_lax(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = LaxReader { bytes, pos: 0 };
+        reader.expect(0x30)?;
+        let sequence_len = reader.byte()?;
+        if sequence_len & 0x80 != 0 {
+            // Long-form sequence length: skip its length bytes without interpreting them
+            reader.skip((sequence_len - 0x80) as usize)?;
+        }
+        let r = reader.integer()?;
+        let s = reader.integer()?;
+        Signature::new(scalar_from_integer(r)?, scalar_from_integer(s)?)
+    }
+}
+
+/// Encodes a 32-byte big-endian value as the content of a minimal, positive DER integer.
+fn der_integer(bytes: &[u8; 32]) -> Vec<u8> {
+    let start = bytes.iter().position(|&byte| byte != 0).unwrap_or(31);
+    let mut integer = Vec::with_capacity(33);
+    if bytes[start] & 0x80 != 0 {
+        integer.push(0x00);
+    }
+    integer.extend_from_slice(&bytes[start..]);
+    integer
+}
+
+/// Checks one strict DER integer element (tag, length and content; the length is already known
+/// to fit) and returns its content.
+fn strict_integer(element: &[u8]) -> Result<&[u8], Error> {
+    let content = &element[2..];
+    if element[0] != 0x02 || content.is_empty() {
+        return Err(Error::InvalidDer);
+    }
+    // Negative numbers are not allowed
+    if content[0] & 0x80 != 0 {
+        return Err(Error::InvalidDer);
+    }
+    // A leading zero is only allowed if the next byte would otherwise read as negative
+    if content.len() > 1 && content[0] == 0x00 && content[1] & 0x80 == 0 {
+        return Err(Error::InvalidDer);
+    }
+    Ok(content)
+}
+
+/// Converts the big-endian content of a DER integer to a scalar, ignoring leading zeros.
+/// Returns `Error::OutOfRange` if the value is not below N.
+fn scalar_from_integer(content: &[u8]) -> Result<Scalar, Error> {
+    let start = content
+        .iter()
+        .position(|&byte| byte != 0)
+        .unwrap_or(content.len());
+    let digits = &content[start..];
+    if digits.len() > 32 {
+        return Err(Error::OutOfRange);
+    }
+    let mut bytes = [0u8; 32];
+    bytes[32 - digits.len()..].copy_from_slice(digits);
+    Scalar::from_be_bytes(&bytes)
+}
+
+/// A cursor over a lax DER encoding; every read fails with `Error::InvalidDer` past the end.
+struct LaxReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> LaxReader<'a> {
+    fn byte(&mut self) -> Result<u8, Error> {
+        let byte = *self.bytes.get(self.pos).ok_or(Error::InvalidDer)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn expect(&mut self, tag: u8) -> Result<(), Error> {
+        match self.byte()? {
+            byte if byte == tag => Ok(()),
+            _ => Err(Error::InvalidDer),
+        }
+    }
+
+    fn skip(&mut self, count: usize) -> Result<(), Error> {
+        self.take(count).map(|_| ())
+    }
+
+    fn take(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        if count > self.bytes.len() - self.pos {
+            return Err(Error::InvalidDer);
+        }
+        let slice = &self.bytes[self.pos..self.pos + count];
+        self.pos += count;
+        Ok(slice)
+    }
+
+    /// Reads an integer element with a short- or long-form length and returns its content.
+    fn integer(&mut self) -> Result<&'a [u8], Error> {
+        self.expect(0x02)?;
+        let len_byte = self.byte()?;
+        let len = if len_byte & 0x80 == 0 {
+            len_byte as usize
+        } else {
+            let len_bytes = self.take((len_byte - 0x80) as usize)?;
+            let start = len_bytes
+                .iter()
+                .position(|&byte| byte != 0)
+                .unwrap_or(len_bytes.len());
+            // A length that does not fit in a usize cannot fit in the input either
+            if len_bytes.len() - start >= size_of::<usize>() {
+                return Err(Error::InvalidDer);
+            }
+            len_bytes[start..]
+                .iter()
+                .fold(0usize, |len, &byte| (len << 8) + byte as usize)
+        };
+        self.take(len)
+    }
+}
@@ -0,0 +1,213 @@
+use crate::ecdsa::Signature;
+use crate::error::Error;
+use crate::scalar::Scalar;
+use crate::test_util::{hex, signature};
+use proptest::prelude::*;
+
+// Helper function for the smallest signature, r = s = 1
+fn one_one() -> Signature {
+    signature("1", "1")
+}
+
+//------------------------------
+// Encoding Vector Tests
+//------------------------------
+
+#[test]
+fn test_der_book_vector() {
+    // Test the book's chapter 4 DER exercise, where only s needs a 0x00 pad byte.
+    let sig = signature(
+        "37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6",
+        "8ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec",
+    );
+    let der = hex(
+        "3045022037206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c60221008ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec",
+    );
+    assert_eq!(sig.to_der(), der);
+    assert_eq!(Signature::from_der(&der).unwrap(), sig);
+    assert_eq!(Signature::from_der_lax(&der).unwrap(), sig);
+}
+
+#[test]
+fn test_der_rfc6979_vector() {
+    // Test the published DER encoding of the RFC 6979 signature of "Satoshi Nakamoto" with key 1.
+    let sig = signature(
+        "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+        "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+    );
+    let der = hex(
+        "3045022100934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d802202442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+    );
+    assert_eq!(sig.to_der(), der);
+    assert_eq!(Signature::from_der(&der).unwrap(), sig);
+}
+
+#[test]
+fn test_der_short_integers() {
+    // Test that small values use minimal lengths, including the 8-byte minimum signature.
+    assert_eq!(one_one().to_der(), hex("3006020101020101"));
+    assert_eq!(signature("80", "7f").to_der(), hex("30070202008002017f"));
+    assert_eq!(
+        Signature::from_der(&hex("30070202008002017f")).unwrap(),
+        signature("80", "7f")
+    );
+}
+
+//------------------------------
+// Strict Parsing Error Tests
+//------------------------------
+
+#[test]
+fn test_from_der_malformed_structure() {
+    // Test that BIP66 rejects bad tags, inconsistent lengths and trailing or missing bytes.
+    let cases = [
+        "",
+        "30",
+        "30050201010201",       // shorter than 8 bytes
+        "3106020101020101",     // wrong sequence tag
+        "3007020101020101",     // sequence length too long
+        "3005020101020101",     // sequence length too short
+        "300602010102010100",   // trailing byte
+        "30810602010102010100", // long-form sequence length
+        "3006020501020101",     // r length runs past the end
+        "3006020101020201",     // s length runs past the end
+        "3006030101020101",     // wrong tag for r
+        "3006020101030101",     // wrong tag for s
+        "3006020002020101",     // zero-length r
+        "3006020201010200",     // zero-length s
+    ];
+    for case in cases {
+        assert_eq!(
+            Signature::from_der(&hex(case)),
+            Err(Error::InvalidDer),
+            "{}",
+            case
+        );
+    }
+    let mut overlong = one_one().to_der();
+    overlong.resize(73, 0);
+    assert_eq!(Signature::from_der(&overlong), Err(Error::InvalidDer));
+}
+
+#[test]
+fn test_from_der_negative_and_padded_integers() {
+    // Test that negative integers and unnecessary leading zeros are rejected in r and s.
+    let cases = [
+        "3006020181020101",     // negative r
+        "3006020101020181",     // negative s
+        "300702020001020101",   // r padded with a zero that is not needed
+        "300702010102020001",   // s padded with a zero that is not needed
+        "30080203000080020101", // r with two leading zeros
+    ];
+    for case in cases {
+        assert_eq!(
+            Signature::from_der(&hex(case)),
+            Err(Error::InvalidDer),
+            "{}",
+            case
+        );
+    }
+}
+
+#[test]
+fn test_from_der_out_of_range() {
+    // Test that well-formed encodings of r = 0 or r = N are rejected as out of range.
+    assert_eq!(
+        Signature::from_der(&hex("3006020100020101")),
+        Err(Error::OutOfRange)
+    );
+    let n = "00fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+    let der = hex(&format!("30260221{}020101", n));
+    assert_eq!(Signature::from_der(&der), Err(Error::OutOfRange));
+    assert_eq!(Signature::from_der_lax(&der), Err(Error::OutOfRange));
+}
+
+//------------------------------
+// Lax Parsing Tests
+//------------------------------
+
+#[test]
+fn test_from_der_lax_accepts_historic_encodings() {
+    // Test encodings rejected by BIP66 that Bitcoin Core's lax parser still reads as r = s = 1.
+    let cases = [
+        "300602010102010100",         // trailing byte
+        "3000020101020101",           // wrong sequence length
+        "30820006020101020101",       // long-form sequence length
+        "30070202000102010100",       // r with an unneeded zero, plus a trailing byte
+        "300a0204000000010201010000", // r padded with three zeros
+        "300702810101020101",         // long-form length for r
+        "300a02820001010282000101",   // long-form lengths for r and s with zero padding
+    ];
+    for case in cases {
+        let bytes = hex(case);
+        assert_eq!(
+            Signature::from_der(&bytes),
+            Err(Error::InvalidDer),
+            "{}",
+            case
+        );
+        assert_eq!(
+            Signature::from_der_lax(&bytes).unwrap(),
+            one_one(),
+            "{}",
+            case
+        );
+    }
+}
+
+#[test]
+fn test_from_der_lax_rejects_truncation() {
+    // Test that the lax parser still needs both integers to be present in full.
+    let cases = [
+        "",
+        "31060201010201",               // wrong sequence tag
+        "3006",                         // no integers
+        "30060201",                     // r truncated
+        "3006020201",                   // r content truncated
+        "300602010103",                 // wrong tag for s
+        "300602010102",                 // s length missing
+        "30060201010202",               // s content missing
+        "3084000006",                   // long-form sequence length past the end
+        "3006028901000000000000000001", // length of r wider than a usize
+    ];
+    for case in cases {
+        assert_eq!(
+            Signature::from_der_lax(&hex(case)),
+            Err(Error::InvalidDer),
+            "{}",
+            case
+        );
+    }
+}
+
+#[test]
+fn test_from_der_lax_overflow() {
+    // Test that an r with more than 32 significant bytes is out of range, while excess
+    // leading zeros are ignored.
+    let mut wide = vec![0x30, 0x27, 0x02, 0x22, 0x01];
+    wide.extend_from_slice(&[0u8; 33]);
+    wide.extend_from_slice(&[0x02, 0x01, 0x01]);
+    assert_eq!(Signature::from_der_lax(&wide), Err(Error::OutOfRange));
+
+    let mut padded = vec![0x30, 0x2a, 0x02, 0x25];
+    padded.extend_from_slice(&[0u8; 36]);
+    padded.push(0x01);
+    padded.extend_from_slice(&[0x02, 0x01, 0x01]);
+    assert_eq!(Signature::from_der_lax(&padded).unwrap(), one_one());
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn prop_der_round_trip(r in any::<[u8; 32]>(), s in any::<[u8; 32]>()) {
+        // Test that every signature survives strict and lax parsing of its DER encoding.
+        let (r, s) = (Scalar::reduce_be_bytes(&r), Scalar::reduce_be_bytes(&s));
+        prop_assume!(!r.is_zero() && !s.is_zero());
+        let sig = Signature::new(r, s).unwrap();
+        let der = sig.to_der();
+        prop_assert!(der.len() <= 72);
+        prop_assert_eq!(Signature::from_der(&der).unwrap(), sig.clone());
+        prop_assert_eq!(Signature::from_der_lax(&der).unwrap(), sig);
+    }
+}
@@ -0,0 +1,171 @@
+// ECDSA over secp256k1 (SEC1 section 4.1), as used by Bitcoin.
+// Signing with private key e, message hash z and nonce k: R = k * G, r = R.x mod N and
+// s = (z + r * e) / k mod N. Verification recomputes R = (z / s) * G + (r / s) * P and
+// accepts if its x-coordinate reduced mod N equals r.
+// (r, s) and (r, N - s) are both valid for the same message, so anyone can flip s. Bitcoin
+// relay policy (BIP62, BIP146) only accepts the low form s <= N / 2, which the signer always emits.
+
+use crate::error::Error;
+use crate::keys::{PrivateKey, PublicKey};
+use crate::limbs::{self, Limbs};
+use crate::point::{G, Point};
+use crate::rfc6979::NonceGenerator;
+use crate::scalar::Scalar;
+use zeroize::Zeroize;
+
+// N / 2, rounded down: the largest s accepted as low
+const SECP256K1_HALF_N: Limbs =
+    limbs::from_hex("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0");
+
+/// An ECDSA signature: the pair (r, s) of non-zero scalars modulo N.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    r: Scalar,
+    s: Scalar,
+}
+
+impl Signature {
+    /// Constructs a signature from its two halves.
+    /// Returns `Error::OutOfRange` if r or s is zero, as no valid signature has them.
+    pub fn new(r: Scalar, s: Scalar) -> Result<Self, Error> {
+        if r.is_zero() || s.is_zero() {
+            return Err(Error::OutOfRange);
+        }
+        Ok(Signature { r, s })
+    }
+
+    /// Returns r, the x-coordinate of the nonce point reduced mod N.
+    pub fn r(&self) -> &Scalar {
+        &self.r
+    }
+
+    /// Returns s = (z + r * e) / k mod N.
+    pub fn s(&self) -> &Scalar {
+        &self.s
+    }
+
+    /// Returns true if s <= N / 2, the form required by Bitcoin relay policy.
+    pub fn is_low_s(&self) -> bool {
+        let (_, borrow) = limbs::sub(&SECP256K1_HALF_N, self.s.limbs());
+        borrow == 0
+    }
+
+    /// Returns true if r < 2^255, so its DER integer needs no 0x00 pad byte.
+    pub fn has_low_r(&self) -> bool {
+        self.r.to_be_bytes()[0] < 0x80
+    }
+
+    /// Returns the equivalent signature with s replaced by N - s if it is above N / 2.
+    /// Both forms verify under the same key and message.
+    pub fn normalize_s(&self) -> Signature {
+        if self.is_low_s() {
+            return self.clone();
+        }
+        Signature {
+            r: self.r.clone(),
+            s: -&self.s,
+        }
+    }
+}
+
+impl PublicKey {
+    /// Checks that `signature` signs the message hash `z` under this key.
+    /// Accepts both the low-S and the high-S form; see `verify_strict`.
+    /// Computes u * G + v * P with u = z / s and v = r / s in one multi-scalar multiplication.
+    pub fn verify(&self, z: &Scalar, signature: &Signature) -> bool {
+        let s_inv = signature.s.inverse();
+        let u = z * &s_inv;
+        let v = &signature.r * &s_inv;
+        match Point::multi_mul(&[(u, G), (v, self.point().clone())]) {
+            Point::Infinity => false,
+            Point::Coordinates { x, .. } => {
+                Scalar::reduce_be_bytes(&x.to_be_bytes()) == signature.r
+            }
+        }
+    }
+
+    /// Like `verify`, but also rejects signatures with s > N / 2, as Bitcoin relay policy does.
+    pub fn verify_strict(&self, z: &Scalar, signature: &Signature) -> bool {
+        signature.is_low_s() && self.verify(z, signature)
+    }
+}
+
+impl PrivateKey {
+    /// Signs the message hash `z` with a deterministic RFC 6979 nonce.
+    /// Signing the same hash twice gives the same signature.
+    pub fn sign(&self, z: &Scalar) -> Signature {
+        self.sign_deterministic(z, None).0
+    }
+
+    /// Signs the message hash `z` with an RFC 6979 nonce that also depends on `extra_entropy`.
+    /// Bitcoin Core uses this to grind for shorter signatures by counting up the extra data.
+    pub fn sign_with_entropy(&self, z: &Scalar, extra_entropy: &[u8; 32]) -> Signature {
+        self.sign_deterministic(z, Some(extra_entropy)).0
+    }
+
+    /// Signs the message hash `z` like Bitcoin Core does: deterministically, retrying with a
+    /// counter as extra entropy (1, 2, ... as 32 little-endian bytes) until r < 2^255.
+    /// The DER encoding is then at most 70 bytes; about half of all hashes need one retry.
+    pub fn sign_low_r(&self, z: &Scalar) -> Signature {
+        let mut signature = self.sign(z);
+        let mut counter: u32 = 0;
+        while !signature.has_low_r() {
+            counter += 1;
+            let mut extra_entropy = [0u8; 32];
+            extra_entropy[..4].copy_from_slice(&counter.to_le_bytes());
+            signature = self.sign_with_entropy(z, &extra_entropy);
+        }
+        signature
+    }
+
+    /// Signs the message hash `z` with the nonce `k`, normalized to low S.
+    /// The nonce must be secret and never reused: two signatures with the same k reveal the key.
+    /// Returns `Error::InvalidNonce` if k is zero or yields r = 0 or s = 0.
+    pub fn sign_with_nonce(&self, z: &Scalar, k: &Scalar) -> Result<Signature, Error> {
+        self.sign_with_nonce_recoverable(z, k)
+            .map(|(signature, _)| signature)
+    }
+
+    /// Signs with RFC 6979 nonces, drawing until one yields a valid signature (the first one does
+    /// except with probability about 2^-256). Also returns the recovery id of the signature.
+    pub(crate) fn sign_deterministic(
+        &self,
+        z: &Scalar,
+        extra_entropy: Option<&[u8; 32]>,
+    ) -> (Signature, u8) {
+        let mut nonces = NonceGenerator::new(self.secret(), z, extra_entropy);
+        loop {
+            let mut k = nonces.next_nonce();
+            let signed = self.sign_with_nonce_recoverable(z, &k);
+            k.zeroize();
+            if let Ok(signed) = signed {
+                return signed;
+            }
+        }
+    }
+
+    /// Signs the message hash `z` with the nonce `k`, normalized to low S, and returns the
+    /// recovery id: bit 0 is the parity of R.y and bit 1 is set if R.x was reduced mod N.
+    fn sign_with_nonce_recoverable(
+        &self,
+        z: &Scalar,
+        k: &Scalar,
+    ) -> Result<(Signature, u8), Error> {
+        if k.is_zero() {
+            return Err(Error::InvalidNonce);
+        }
+        let (x, y) = match Point::mul_generator(k) {
+            Point::Infinity => return Err(Error::InvalidNonce),
+            Point::Coordinates { x, y } => (x.to_be_bytes(), y),
+        };
+        let r = Scalar::reduce_be_bytes(&x);
+        let mut recovery_id = (y.is_odd() as u8) | (((r.to_be_bytes() != x) as u8) << 1);
+        let s = &(z + &(&r * self.secret())) * &k.inverse();
+        let signature = Signature::new(r, s).map_err(|_| Error::InvalidNonce)?;
+        // Replacing s by N - s matches the nonce -k, whose point -R has the opposite y parity
+        if !signature.is_low_s() {
+            recovery_id ^= 1;
+        }
+        Ok((signature.normalize_s(), recovery_id))
+    }
+}
@@ -0,0 +1,251 @@
+use crate::ecdsa::Signature;
+use crate::error::Error;
+use crate::finite_fields::FieldElement;
+use crate::keys::{PrivateKey, PublicKey};
+use crate::point::Point;
+use crate::scalar::Scalar;
+use crate::test_util::{private_key, scalar, signature};
+use num_bigint::BigInt;
+use proptest::prelude::*;
+
+//------------------------------
+// Verification Vector Tests
+//------------------------------
+
+#[test]
+fn test_verify_book_vectors() {
+    // Test the two signatures of the book's chapter 3 verification exercise.
+    let x =
+        FieldElement::from_hex("887387e452b8eacc4acfde10d9aaf7f6d9a0f975aabb10d006e4da568744d06c");
+    let y =
+        FieldElement::from_hex("61de6d95231cd89026e286df3b6ae4a894a3378e393e93a0f45b666329a0ae34");
+    let point = Point::new(Some(x.unwrap()), Some(y.unwrap())).unwrap();
+    let public_key = PublicKey::from_point(point).unwrap();
+    let cases = [
+        (
+            "ec208baa0fc1c19f708a9ca96fdeff3ac3f230bb4a7ba4aede4942ad003c0f60",
+            "ac8d1c87e51d0d441be8b3dd5b05c8795b48875dffe00b7ffcfac23010d3a395",
+            "068342ceff8935ededd102dd876ffd6ba72d6a427a3edb13d26eb0781cb423c4",
+        ),
+        (
+            "7c076ff316692a3d7eb3c3bb0f8b1488cf72e1afcd929e29307032997a838a3d",
+            "00eff69ef2b1bd93a66ed5219add4fb51e11a840f404876325a1e8ffe0529a2c",
+            "c7207fee197d27c618aea621406f6bf5ef6fca38681d82b2f06fddbdce6feab6",
+        ),
+    ];
+    for (z, r, s) in cases {
+        assert!(public_key.verify(&scalar(z), &signature(r, s)));
+    }
+}
+
+#[test]
+fn test_verify_libsecp256k1_vector() {
+    // Test a signature from the libsecp256k1 (Bitcoin Core) test suite. Its s is in the upper
+    // half of the range, which plain ECDSA accepts; so does its negation N - s.
+    let public_key = PublicKey::from_point(
+        Point::from_hex("031ee99d2b786ab3b0991325f2de8489246a6a3fdb700f6d0511b1d80cf5f4cd43")
+            .unwrap(),
+    )
+    .unwrap();
+    let z = scalar("a4965ca63b7d8562736ceec36dfa5a11bf426eb65be8ea3f7a49ae363032da0d");
+    let r = scalar("839c1fbc5304de944f697c9f4b1d01d1faeba32d751c0f7acb21ac8a0f436a72");
+    let s = scalar("e89bd46bb3a5a62adc679f659b7ce876d83ee297c7a5587b2011c4fcc72eab45");
+    assert!(public_key.verify(&z, &Signature::new(r.clone(), s.clone()).unwrap()));
+    assert!(public_key.verify(&z, &Signature::new(r, -s).unwrap()));
+}
+
+#[test]
+fn test_verify_rejects_tampering() {
+    // Test that changing the message, r, s or the key makes verification fail.
+    let key = private_key(12345);
+    let z = scalar("969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48");
+    let k = scalar("499602d2");
+    let sig = key.sign_with_nonce(&z, &k).unwrap();
+    let one = Scalar::one();
+    assert!(key.public_key().verify(&z, &sig));
+    assert!(!key.public_key().verify(&(&z + &one), &sig));
+    let bad_r = Signature::new(sig.r() + &one, sig.s().clone()).unwrap();
+    assert!(!key.public_key().verify(&z, &bad_r));
+    let bad_s = Signature::new(sig.r().clone(), sig.s() + &one).unwrap();
+    assert!(!key.public_key().verify(&z, &bad_s));
+    assert!(!private_key(12346).public_key().verify(&z, &sig));
+}
+
+//------------------------
+// Signing Vector Tests
+//------------------------
+
+#[test]
+fn test_sign_book_vectors() {
+    // Test the book's chapter 3 signing exercises, which use the fixed nonce k = 1234567890.
+    let k = Scalar::new(BigInt::from(1234567890)).unwrap();
+
+    // e = 12345, z = hash256("Programming Bitcoin!")
+    let key = private_key(12345);
+    let z = scalar("969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48");
+    let sig = key.sign_with_nonce(&z, &k).unwrap();
+    assert_eq!(
+        sig,
+        signature(
+            "2b698a0f0a4041b77e63488ad48c23e8e8838dd1fb7520408b121697b782ef22",
+            "1dbc63bfef4416705e602a7b564161167076d8b20990a0f26f316cff2cb0bc1a",
+        )
+    );
+    assert_eq!(
+        key.public_key().point().to_hex(false),
+        "04f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f0eba29d0f0c5408ed681984dc525982abefccd9f7ff01dd26da4999cf3f6a295"
+    );
+
+    // e = hash256("my secret"), z = hash256("my message"). The book prints the high-S form
+    // bb14e602...; the signer returns its low-S equivalent N - s.
+    let key = PrivateKey::new(scalar(
+        "8b387de39861728c92ec9f589c303b1038ff60eb3963b12cd212263a1d1e0f00",
+    ))
+    .unwrap();
+    let z = scalar("0231c6f3d980a6b0fb7152f85cee7eb52bf92433d9919b9c5218cb08e79cce78");
+    let sig = key.sign_with_nonce(&z, &k).unwrap();
+    assert_eq!(
+        sig,
+        signature(
+            "2b698a0f0a4041b77e63488ad48c23e8e8838dd1fb7520408b121697b782ef22",
+            "44eb19fd1061c078d1da052cd7b994c9d43b916c9f7b4789d46f0a44d087b488",
+        )
+    );
+}
+
+#[test]
+fn test_sign_and_verify() {
+    // Test that signatures from `sign` verify, and that signing is deterministic.
+    let key = private_key(0xdeadbeef);
+    let z = scalar("c90c269c4f8fcbe6880f72a721ddfbf1914268a794cbb21cfafee13770ae19f1");
+    let first = key.sign(&z);
+    let second = key.sign(&z);
+    assert!(key.public_key().verify(&z, &first));
+    assert_eq!(first, second);
+}
+
+//------------------------
+// Low-S Tests
+//------------------------
+
+#[test]
+fn test_strict_verification_rejects_high_s() {
+    // Test that both forms of the libsecp256k1 vector verify in lax mode, but only the
+    // low-S form passes strict verification.
+    let public_key = PublicKey::from_point(
+        Point::from_hex("031ee99d2b786ab3b0991325f2de8489246a6a3fdb700f6d0511b1d80cf5f4cd43")
+            .unwrap(),
+    )
+    .unwrap();
+    let z = scalar("a4965ca63b7d8562736ceec36dfa5a11bf426eb65be8ea3f7a49ae363032da0d");
+    let high = signature(
+        "839c1fbc5304de944f697c9f4b1d01d1faeba32d751c0f7acb21ac8a0f436a72",
+        "e89bd46bb3a5a62adc679f659b7ce876d83ee297c7a5587b2011c4fcc72eab45",
+    );
+    let low = high.normalize_s();
+    assert!(!high.is_low_s());
+    assert!(low.is_low_s());
+    assert_eq!(
+        low.s(),
+        &scalar("17642b944c5a59d52398609a64831787e26ffa4ee7a347c09fc09990090795fc")
+    );
+    assert!(public_key.verify(&z, &high));
+    assert!(public_key.verify(&z, &low));
+    assert!(!public_key.verify_strict(&z, &high));
+    assert!(public_key.verify_strict(&z, &low));
+}
+
+#[test]
+fn test_low_s_boundary() {
+    // Test that s = N / 2 is low and s = N / 2 + 1 is high.
+    let half = scalar("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0");
+    let one = Scalar::one();
+    let at_half = Signature::new(one.clone(), half.clone()).unwrap();
+    let above_half = Signature::new(one.clone(), &half + &one).unwrap();
+    assert!(at_half.is_low_s());
+    assert!(!above_half.is_low_s());
+    assert_eq!(at_half.normalize_s(), at_half);
+    // N - (N / 2 + 1) = N / 2, since N is odd
+    assert_eq!(above_half.normalize_s(), at_half);
+}
+
+#[test]
+fn test_signer_emits_low_s() {
+    // Test that signatures from `sign` are always low-S and pass strict verification.
+    let key = private_key(0xdeadbeef);
+    for i in 0u64..16 {
+        let z = Scalar::new(BigInt::from(i)).unwrap();
+        let signature = key.sign(&z);
+        assert!(signature.is_low_s());
+        assert!(key.public_key().verify_strict(&z, &signature));
+    }
+}
+
+#[test]
+fn test_sign_low_r() {
+    // Test that low-R signing keeps the plain signature when r < 2^255 and otherwise grinds
+    // with a counter, giving DER encodings of at most 70 bytes.
+    let key = private_key(0xdeadbeef);
+    let mut ground = 0;
+    for i in 0u64..16 {
+        let z = Scalar::new(BigInt::from(i)).unwrap();
+        let plain = key.sign(&z);
+        let low_r = key.sign_low_r(&z);
+        assert!(low_r.has_low_r() && low_r.is_low_s());
+        assert!(low_r.to_der().len() <= 70);
+        assert!(key.public_key().verify_strict(&z, &low_r));
+        if plain.has_low_r() {
+            assert_eq!(low_r, plain);
+        } else {
+            assert_ne!(low_r, plain);
+            ground += 1;
+        }
+    }
+    assert!(ground > 0);
+}
+
+//------------------------
+// Validation Tests
+//------------------------
+
+#[test]
+fn test_zero_values_rejected() {
+    // Test that r = 0, s = 0, a zero secret and the point at infinity are rejected.
+    let one = Scalar::one();
+    assert_eq!(
+        Signature::new(Scalar::zero(), one.clone()),
+        Err(Error::OutOfRange)
+    );
+    assert_eq!(Signature::new(one, Scalar::zero()), Err(Error::OutOfRange));
+    assert_eq!(PrivateKey::new(Scalar::zero()), Err(Error::OutOfRange));
+    assert_eq!(
+        PublicKey::from_point(Point::Infinity),
+        Err(Error::PointAtInfinity)
+    );
+}
+
+#[test]
+fn test_zero_nonce_rejected() {
+    // Test that signing with k = 0 fails instead of dividing by zero.
+    let key = private_key(1);
+    assert_eq!(
+        key.sign_with_nonce(&Scalar::one(), &Scalar::zero()),
+        Err(Error::InvalidNonce)
+    );
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn prop_sign_verify_round_trip(e in any::<[u8; 32]>(), z in any::<[u8; 32]>(), k in any::<[u8; 32]>()) {
+        // Test that signatures with arbitrary keys, hashes and nonces verify.
+        let secret = Scalar::reduce_be_bytes(&e);
+        prop_assume!(!secret.is_zero());
+        let key = PrivateKey::new(secret).unwrap();
+        let z = Scalar::reduce_be_bytes(&z);
+        if let Ok(sig) = key.sign_with_nonce(&z, &Scalar::reduce_be_bytes(&k)) {
+            prop_assert!(key.public_key().verify(&z, &sig));
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// Crate-wide error type, so callers can branch on the kind of failure instead of parsing messages.
+
+use core::fmt;
+
+/// Errors returned by the field, point and encoding APIs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An integer is negative or not below the modulus of the field it was meant for.
+    OutOfRange,
+    /// The coordinates do not satisfy the curve equation.
+    NotOnCurve,
+    /// Only one of the two coordinates of a point was given.
+    MismatchedCoordinates,
+    /// Division by zero: zero has no multiplicative inverse.
+    DivisionByZero,
+    /// An encoding has the wrong length; holds the length received.
+    InvalidLength(usize),
+    /// An encoding starts with an unknown prefix byte; holds the byte received.
+    InvalidPrefix(u8),
+    /// An encoding represents the point at infinity where a finite point is required.
+    PointAtInfinity,
+    /// A string contains non-hex characters or has the wrong number of digits.
+    InvalidHex,
+    /// A string does not follow the format produced by `Display`.
+    InvalidFormat,
+    /// A DER signature encoding is malformed or, for strict parsing, violates BIP66.
+    InvalidDer,
+    /// The operating system's random number generator failed.
+    RandomSourceUnavailable,
+    /// A signing nonce is zero or yields r = 0 or s = 0; signing must be retried with another nonce.
+    InvalidNonce,
+    /// A string contains characters outside the Base58 alphabet or is too short for a checksum.
+    InvalidBase58,
+    /// A string is not valid standard Base64.
+    InvalidBase64,
+    /// A string is not a well-formed Bech32 or Bech32m segwit address.
+    InvalidBech32,
+    /// A Base58Check or Bech32 checksum does not match the data.
+    InvalidChecksum,
+    /// An address is well formed but of a type or network this crate does not handle.
+    UnsupportedAddress,
+    /// A serialized transaction or witness stack is truncated or malformed.
+    InvalidTransaction,
+    /// A private key does not control the address it was asked to sign for.
+    KeyMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::OutOfRange => write!(f, "value is not in the field range"),
+            Error::NotOnCurve => write!(f, "point is not on the curve"),
+            Error::MismatchedCoordinates => write!(
+                f,
+                "invalid point: both coordinates must be Some or both must be None"
+            ),
+            Error::DivisionByZero => write!(f, "division by zero"),
+            Error::InvalidLength(length) => write!(f, "invalid encoding length: {} bytes", length),
+            Error::InvalidPrefix(prefix) => write!(f, "invalid encoding prefix: 0x{:02x}", prefix),
+            Error::PointAtInfinity => write!(f, "unexpected point at infinity"),
+            Error::InvalidHex => write!(f, "invalid hex string"),
+            Error::InvalidFormat => write!(f, "string does not match the display format"),
+            Error::InvalidDer => write!(f, "invalid DER signature encoding"),
+            Error::RandomSourceUnavailable => write!(f, "random number generator unavailable"),
+            Error::InvalidNonce => write!(f, "nonce is not usable for signing"),
+            Error::InvalidBase58 => write!(f, "invalid base58 string"),
+            Error::InvalidBase64 => write!(f, "invalid base64 string"),
+            Error::InvalidBech32 => write!(f, "invalid bech32 address"),
+            Error::InvalidChecksum => write!(f, "checksum mismatch"),
+            Error::UnsupportedAddress => write!(f, "unsupported address type"),
+            Error::InvalidTransaction => write!(f, "invalid transaction encoding"),
+            Error::KeyMismatch => write!(f, "key does not match the address"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
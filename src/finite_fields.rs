@@ -1,67 +1,280 @@
-use num_bigint::BigInt;
-use num_traits::{One, Signed, Zero};
-use std::fmt;
-use std::ops::{Add, Div, Mul, Neg, Sub};
-
-// Defines the secp256k1 prime (p = 2^256 - 2^32 - 977) as a global constant.
-// This is the modulus for our finite field F_p.
-lazy_static::lazy_static! {
-    static ref PRIME: BigInt = BigInt::parse_bytes(
-        b"fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
-        16
-    ).unwrap();
-}
-
-/// Represents an element in the finite field F_p, where p is the secp256k1 prime.
-/// Elements are integers modulo p, satisfying 0 <= num < p.
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct FieldElement {
-    num: BigInt,
-}
-
-impl FieldElement {
-    /// Constructs a new `FieldElement`, ensuring the value is in the valid range [0, p-1].
-    /// Returns an error if `num` is negative or greater than or equal to the prime modulus.
-    pub fn new(num: BigInt) -> Result<Self, String> {
-        if num.is_negative() || num >= *PRIME {
-            return Err(format!(
-                "Number {} not in the field range 0 to {}",
-                num,
-                &*PRIME - BigInt::one()
-            ));
-        }
-        Ok(FieldElement { num })
-    }
-
-    /// Returns a reference to the field's prime modulus (p).
+use crate::error::Error;
+use crate::limbs::{self, Limbs, WideLimbs};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::str::FromStr;
+use num_traits::{One, Signed};
+use once_cell::race::OnceBox;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
+
+// Re-exported so callers can construct field elements without naming num-bigint themselves.
+pub use num_bigint::BigInt;
+
+/// Describes a prime field F_p by its modulus.
+/// Implemented by zero-sized marker types, so elements of different fields are distinct types
+/// and cannot be mixed in arithmetic. The modulus must be a prime number.
+pub trait PrimeField: Clone + fmt::Debug + PartialEq + Eq {
+    /// Name used as the prefix when displaying elements of this field.
+    const NAME: &'static str = "FieldElement";
+
+    /// The prime modulus p as four little-endian 64-bit limbs.
+    const MODULUS: [u64; 4];
+
+    /// Returns the prime modulus p of the field as a `BigInt`.
+    /// Implementations keep it in a static `ModulusCell`, as `prime_field!` does.
+    fn modulus() -> &'static BigInt;
+
+    /// Reduces a 512-bit product (eight little-endian limbs) modulo p.
+    /// The default works for any p < 2^256; fields with a special-form prime override it.
+    fn reduce_wide(wide: &[u64; 8]) -> [u64; 4] {
+        limbs::reduce_wide_generic(wide, &Self::MODULUS)
+    }
+}
+
+/// Parses a big-endian hex string into the little-endian limbs expected by `PrimeField::MODULUS`.
+/// Usable in const context, so moduli can be written as hex literals.
+pub const fn modulus_limbs(hex: &str) -> [u64; 4] {
+    limbs::from_hex(hex)
+}
+
+/// Holds the `BigInt` form of a field modulus, built on first use, so `PrimeField::modulus`
+/// can return a `&'static` reference without std.
+#[derive(Debug, Default)]
+pub struct ModulusCell(OnceBox<BigInt>);
+
+impl ModulusCell {
+    /// Creates an empty cell, for use in a `static`.
+    pub const fn new() -> Self {
+        ModulusCell(OnceBox::new())
+    }
+
+    /// Returns the modulus given by `limbs`, converting it on the first call.
+    pub fn get(&'static self, limbs: &[u64; 4]) -> &'static BigInt {
+        self.0.get_or_init(|| Box::new(limbs::to_bigint(limbs)))
+    }
+}
+
+/// The secp256k1 prime p = 2^256 - 2^32 - 977 as little-endian limbs, the modulus of `FieldElement`.
+pub const SECP256K1_P: [u64; 4] =
+    modulus_limbs("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+
+// c = 2^256 - p = 2^32 + 977 for the secp256k1 prime.
+const SECP256K1_P_C: u64 = 0x1_0000_03d1;
+
+/// Marker type for the secp256k1 base field F_p, p = 2^256 - 2^32 - 977.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Secp256k1Field;
+
+impl PrimeField for Secp256k1Field {
+    const MODULUS: [u64; 4] = SECP256K1_P;
+
+    fn modulus() -> &'static BigInt {
+        static MODULUS: ModulusCell = ModulusCell::new();
+        MODULUS.get(&Self::MODULUS)
+    }
+
+    /// Uses the special form p = 2^256 - 2^32 - 977 to reduce with two cheap folds.
+    fn reduce_wide(wide: &WideLimbs) -> Limbs {
+        limbs::reduce_wide_special_limb(wide, SECP256K1_P_C, &Self::MODULUS)
+    }
+}
+
+/// Declares a marker type implementing `PrimeField` for the prime given as a hex string.
+/// Used for small fields from the book exercises, e.g. `prime_field!(pub F223, "df");`.
+#[macro_export]
+macro_rules! prime_field {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $hex:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        $vis struct $name;
+
+        impl $crate::finite_fields::PrimeField for $name {
+            const MODULUS: [u64; 4] = $crate::finite_fields::modulus_limbs($hex);
+
+            fn modulus() -> &'static $crate::finite_fields::BigInt {
+                static MODULUS: $crate::finite_fields::ModulusCell =
+                    $crate::finite_fields::ModulusCell::new();
+                MODULUS.get(&Self::MODULUS)
+            }
+        }
+    };
+}
+
+/// Represents an element in the finite field F_p described by `F`.
+/// Elements are integers modulo p, satisfying 0 <= num < p, stored as four 64-bit limbs on the stack.
+/// Addition, subtraction, multiplication and equality run in constant time with respect to the
+/// element values, and so does inversion of a nonzero element (inverting zero panics, which reveals
+/// it); only `pow` with a secret exponent and the `BigInt` conversions are variable-time.
+#[derive(Clone)]
+pub struct Fp<F: PrimeField> {
+    limbs: Limbs,
+    // The value as a `BigInt`, built on the first call to `num` so it can be returned by reference
+    num: OnceBox<BigInt>,
+    field: PhantomData<F>,
+}
+
+/// An element of the secp256k1 base field F_p, where p is the secp256k1 prime.
+pub type FieldElement = Fp<Secp256k1Field>;
+
+impl<F: PrimeField> Fp<F> {
+    /// Constructs a new field element, ensuring the value is in the valid range [0, p-1].
+    /// Returns `Error::OutOfRange` if `num` is negative or greater than or equal to the prime modulus.
+    pub fn new(num: BigInt) -> Result<Self, Error> {
+        if num.is_negative() || num.bits() > 256 {
+            return Err(Error::OutOfRange);
+        }
+        Self::from_be_bytes(&limbs::to_be_bytes(&limbs::from_bigint(&num)))
+    }
+
+    /// Reduces an arbitrary (possibly negative or oversized) integer into the field: num mod p.
+    pub fn reduce(num: &BigInt) -> Self {
+        let modulus = F::modulus();
+        let mut reduced = num % modulus;
+        if reduced.is_negative() {
+            reduced += modulus;
+        }
+        Self::from_limbs(limbs::from_bigint(&reduced))
+    }
+
+    /// Wraps limbs already known to encode a value in [0, p-1] without re-checking the range.
+    pub(crate) const fn from_limbs(limbs: Limbs) -> Self {
+        Fp {
+            limbs,
+            num: OnceBox::new(),
+            field: PhantomData,
+        }
+    }
+
+    /// Returns the little-endian limbs of the canonical representative.
+    pub(crate) fn limbs(&self) -> &Limbs {
+        &self.limbs
+    }
+
+    /// Parses a 32-byte big-endian integer as a field element.
+    /// Returns `Error::OutOfRange` if the value is not below p.
+    pub fn from_be_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
+        let value = limbs::from_be_bytes(bytes);
+        // value - p borrows exactly when value < p
+        if limbs::sub(&value, &F::MODULUS).1 == 0 {
+            return Err(Error::OutOfRange);
+        }
+        Ok(Self::from_limbs(value))
+    }
+
+    /// Interprets 32 big-endian bytes (e.g., a message hash) as an integer and reduces it modulo p.
+    pub fn reduce_be_bytes(bytes: &[u8; 32]) -> Self {
+        let value = limbs::from_be_bytes(bytes);
+        let wide = [value[0], value[1], value[2], value[3], 0, 0, 0, 0];
+        Self::from_limbs(F::reduce_wide(&wide))
+    }
+
+    /// Serializes the element as a 32-byte big-endian integer.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        limbs::to_be_bytes(&self.limbs)
+    }
+
+    /// Parses a big-endian hex string of 1 to 64 digits, with an optional "0x" prefix.
+    /// Returns `Error::InvalidHex` for malformed input and `Error::OutOfRange` if the value is not below p.
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        let digits = hex.strip_prefix("0x").unwrap_or(hex);
+        if digits.is_empty() || digits.len() > 64 {
+            return Err(Error::InvalidHex);
+        }
+        let bytes = crate::hex::decode(&format!("{:0>64}", digits))?;
+        Self::from_be_bytes(&bytes.try_into().unwrap())
+    }
+
+    /// Returns the element as 64 lowercase hex digits (big-endian, no prefix).
+    pub fn to_hex(&self) -> String {
+        crate::hex::encode(&self.to_be_bytes())
+    }
+
+    /// Returns the field's prime modulus (p).
     pub fn prime() -> &'static BigInt {
-        &PRIME
+        F::modulus()
     }
 
-    /// Returns a reference to the internal number representing the field element.
+    /// Returns the number representing the field element as a `BigInt`.
+    /// The conversion runs on the first call and is cached in the element; internal code
+    /// that may handle secrets uses the uncached `to_bigint` instead.
     pub fn num(&self) -> &BigInt {
-        &self.num
+        self.num
+            .get_or_init(|| Box::new(limbs::to_bigint(&self.limbs)))
+    }
+
+    /// Converts the element to a new `BigInt` without caching it.
+    pub(crate) fn to_bigint(&self) -> BigInt {
+        limbs::to_bigint(&self.limbs)
     }
 
     /// Returns the zero element (0) in the field.
     pub fn zero() -> Self {
-        FieldElement::new(BigInt::zero()).unwrap()
+        Self::from_limbs(limbs::ZERO)
     }
 
     /// Returns the one element (1) in the field.
     pub fn one() -> Self {
-        FieldElement::new(BigInt::one()).unwrap()
+        Self::from_limbs(limbs::ONE)
+    }
+
+    /// Returns true if this is the zero element.
+    pub fn is_zero(&self) -> bool {
+        limbs::is_zero(&self.limbs)
+    }
+
+    /// Returns `b` if `choice` is set and `a` otherwise, without branching on `choice`.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::from_limbs(limbs::select(&a.limbs, &b.limbs, choice))
+    }
+
+    /// Swaps `a` and `b` if `choice` is set, without branching on `choice`.
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        let new_a = Self::conditional_select(a, b, choice);
+        let new_b = Self::conditional_select(b, a, choice);
+        *a = new_a;
+        *b = new_b;
+    }
+
+    /// Computes the square a^2 mod p.
+    pub fn square(&self) -> Self {
+        self * self
     }
 
     /// Computes the multiplicative inverse using Fermat's Little Theorem: a^(p-2) ≡ a^(-1) mod p.
-    /// Panics if the element is zero, as zero has no multiplicative inverse.
-    fn inverse(&self) -> Self {
-        if self.num == BigInt::zero() {
+    /// Panics if the element is zero, as zero has no multiplicative inverse; see `checked_div`.
+    pub fn inverse(&self) -> Self {
+        if self.is_zero() {
             panic!("Division by zero: no multiplicative inverse exists");
         }
-        let exponent = FieldElement::prime() - BigInt::from(2);
-        let result = self.num.modpow(&exponent, FieldElement::prime());
-        FieldElement { num: result }
+        let exponent = limbs::sub(&F::MODULUS, &[2, 0, 0, 0]).0;
+        self.pow_limbs(&exponent)
+    }
+
+    /// Computes self / rhs, returning `Error::DivisionByZero` instead of panicking when rhs is zero.
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self, Error> {
+        if rhs.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(self / rhs)
+    }
+
+    /// Computes a^e mod p by left-to-right square-and-multiply, starting at the exponent's top bit.
+    fn pow_limbs(&self, exponent: &Limbs) -> Self {
+        let mut result = Self::one();
+        let top_bit = (0..256).rev().find(|&i| limbs::bit(exponent, i));
+        for i in (0..top_bit.map_or(0, |bit| bit + 1)).rev() {
+            result = result.square();
+            if limbs::bit(exponent, i) {
+                result = &result * self;
+            }
+        }
+        result
     }
 
     /// Computes exponentiation: a^n mod p, where n is reduced modulo (p-1) per Fermat's Little Theorem.
@@ -75,131 +288,325 @@ impl FieldElement {
             self.inverse().pow(reduced_exp)
         } else {
             let reduced_exp = exponent % &p_minus_one;
-            let num = self.num.modpow(&reduced_exp, Self::prime());
-            FieldElement { num }
+            self.pow_limbs(&limbs::from_bigint(&reduced_exp))
+        }
+    }
+
+    /// Returns true if the canonical representative in [0, p-1] is even.
+    /// Used to pick between the two square roots y and p - y (e.g., SEC1 and BIP340 encodings).
+    pub fn is_even(&self) -> bool {
+        self.limbs[0] & 1 == 0
+    }
+
+    /// Returns true if the canonical representative in [0, p-1] is odd.
+    pub fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    /// Returns the parity of the canonical representative as a `Choice`, for selecting on the
+    /// parity of a secret value without branching on it.
+    pub fn is_odd_choice(&self) -> Choice {
+        Choice::from((self.limbs[0] & 1) as u8)
+    }
+
+    /// Computes the Legendre symbol (a/p) by Euler's criterion: a^((p-1)/2) mod p.
+    /// Returns 1 for non-zero squares, -1 for non-squares and 0 for zero.
+    pub fn legendre_symbol(&self) -> i8 {
+        let half = limbs::shr(&F::MODULUS, 1);
+        let result = self.pow_limbs(&half);
+        if result.is_zero() {
+            0
+        } else if result == Self::one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Returns true if the element is a quadratic residue (has a square root), including zero.
+    pub fn is_square(&self) -> bool {
+        self.legendre_symbol() >= 0
+    }
+
+    /// Computes a square root r with r^2 = a, or returns `None` if a is not a square.
+    /// For p ≡ 3 (mod 4), as for secp256k1, this is the single exponentiation a^((p+1)/4);
+    /// other primes use the Tonelli–Shanks algorithm. Either root may be returned; use
+    /// `is_even` and `negate` to choose a specific one. Runs in variable time.
+    pub fn sqrt(&self) -> Option<Self> {
+        let root = if F::MODULUS[0] & 3 == 3 {
+            // (p + 1) / 4 = (p >> 2) + 1 since p ≡ 3 (mod 4)
+            let exponent = limbs::add(&limbs::shr(&F::MODULUS, 2), &limbs::ONE).0;
+            self.pow_limbs(&exponent)
+        } else {
+            self.tonelli_shanks()?
+        };
+        if &root * &root == *self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    /// Tonelli–Shanks square root for general odd primes; returns `None` for non-squares.
+    fn tonelli_shanks(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        if self.legendre_symbol() != 1 {
+            return None;
+        }
+
+        // Write p - 1 = q * 2^s with q odd
+        let p_minus_one = limbs::sub(&F::MODULUS, &limbs::ONE).0;
+        let mut s = 0;
+        let mut q = p_minus_one;
+        while q[0] & 1 == 0 {
+            q = limbs::shr(&q, 1);
+            s += 1;
+        }
+
+        // Find a quadratic non-residue z by trial: 2, 3, 4, ...
+        let mut z = Self::one() + Self::one();
+        while z.legendre_symbol() != -1 {
+            z = &z + &Self::one();
         }
+
+        let mut m = s;
+        let mut c = z.pow_limbs(&q);
+        let mut t = self.pow_limbs(&q);
+        let q_plus_one_half = limbs::shr(&limbs::add(&q, &limbs::ONE).0, 1);
+        let mut r = self.pow_limbs(&q_plus_one_half);
+
+        // Invariant: r^2 = a * t, and t has order dividing 2^(m-1)
+        while t != Self::one() {
+            // Find the least i with t^(2^i) = 1
+            let mut i = 0;
+            let mut t_pow = t.clone();
+            while t_pow != Self::one() {
+                t_pow = t_pow.square();
+                i += 1;
+            }
+            let mut b = c.clone();
+            for _ in 0..(m - i - 1) {
+                b = b.square();
+            }
+            m = i;
+            c = b.square();
+            t = &t * &c;
+            r = &r * &b;
+        }
+        Some(r)
     }
 
     /// Computes the additive inverse of the field element: -a = p - a mod p.
-    pub fn negate(&self) -> FieldElement {
-        let p = Self::prime();
-        let neg_num = (p - &self.num) % p;
-        FieldElement::new(neg_num).unwrap()
+    pub fn negate(&self) -> Self {
+        Self::from_limbs(limbs::sub_mod(&limbs::ZERO, &self.limbs, &F::MODULUS))
+    }
+}
+
+/// Inverts every non-zero element of `elements` in place using Montgomery's trick:
+/// one field inversion plus 3(n-1) multiplications instead of n inversions.
+/// Zero elements have no inverse; they are skipped explicitly and left as zero.
+pub fn batch_inverse<F: PrimeField>(elements: &mut [Fp<F>]) {
+    // prefix[i] holds the product of all non-zero elements before index i (None if there are none)
+    let mut prefix = Vec::with_capacity(elements.len());
+    let mut acc: Option<Fp<F>> = None;
+    for element in elements.iter() {
+        prefix.push(acc.clone());
+        if !element.is_zero() {
+            acc = Some(match acc {
+                Some(product) => &product * element,
+                None => element.clone(),
+            });
+        }
+    }
+
+    // All elements are zero (or the slice is empty): nothing to invert
+    let Some(product) = acc else {
+        return;
+    };
+
+    // Walk backwards, peeling one element at a time off the inverted running product
+    let mut inv = product.inverse();
+    for (element, before) in elements.iter_mut().zip(prefix).rev() {
+        if element.is_zero() {
+            continue;
+        }
+        match before {
+            Some(before) => {
+                let element_inv = &inv * &before;
+                inv = &inv * element;
+                *element = element_inv;
+            }
+            None => {
+                // The first non-zero element: the remaining product is exactly its inverse
+                *element = inv;
+                break;
+            }
+        }
+    }
+}
+
+/// Compares two field elements in constant time by OR-ing the differences of all limbs.
+impl<F: PrimeField> ConstantTimeEq for Fp<F> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let diff = (0..4).fold(0, |acc, i| acc | (self.limbs[i] ^ other.limbs[i]));
+        diff.ct_eq(&0)
+    }
+}
+
+/// Overwrites the element with zero in a way the compiler does not optimize away,
+/// so secrets such as private keys can be wiped from memory.
+impl<F: PrimeField> Zeroize for Fp<F> {
+    fn zeroize(&mut self) {
+        self.limbs.zeroize();
+        // num-bigint cannot wipe its heap buffer, so the cached `BigInt` is only dropped;
+        // like every `BigInt` conversion, `num` is not meant for secrets
+        self.num = OnceBox::new();
+    }
+}
+
+impl<F: PrimeField> fmt::Debug for Fp<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Fp")
+            .field("limbs", &self.limbs)
+            .field("field", &self.field)
+            .finish()
+    }
+}
+
+/// Equality goes through `ct_eq`, so comparing secret-derived values does not leak where they differ.
+impl<F: PrimeField> PartialEq for Fp<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
     }
 }
 
-/// Formats a `FieldElement` as a hex string with the modulus, e.g., "FieldElement_0x..._(mod 0x...)".
+impl<F: PrimeField> Eq for Fp<F> {}
+
+/// Formats a field element as a hex string with the modulus, e.g., "FieldElement_0x..._(mod 0x...)".
 /// Useful for debugging and logging.
-impl fmt::Display for FieldElement {
+impl<F: PrimeField> fmt::Display for Fp<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "FieldElement_0x{:064x}_(mod 0x{:064x})",
-            self.num, *PRIME
+            "{}_0x{:064x}_(mod 0x{:064x})",
+            F::NAME,
+            self.num(),
+            F::modulus()
         )
     }
 }
 
-/// Implements addition for references to `FieldElement`, computing (a + b) mod p efficiently.
-/// Avoids unnecessary modular reductions by checking if the sum exceeds p.
-impl<'a> Add<&'a FieldElement> for &FieldElement {
-    type Output = FieldElement;
-    fn add(self, rhs: &'a FieldElement) -> FieldElement {
-        let mut result = &self.num + &rhs.num;
-        if result >= *FieldElement::prime() {
-            result -= FieldElement::prime();
+/// Parses the format produced by `Display`: `{NAME}_0x{value}_(mod 0x{modulus})`.
+/// The name and modulus must match the field `F`; the value must be below the modulus.
+impl<F: PrimeField> FromStr for Fp<F> {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (value, modulus) = s
+            .strip_prefix(F::NAME)
+            .and_then(|rest| rest.strip_prefix("_0x"))
+            .and_then(|rest| rest.strip_suffix(')'))
+            .and_then(|rest| rest.split_once("_(mod 0x"))
+            .ok_or(Error::InvalidFormat)?;
+        if !modulus.eq_ignore_ascii_case(&crate::hex::encode(&limbs::to_be_bytes(&F::MODULUS))) {
+            return Err(Error::InvalidFormat);
         }
-        FieldElement { num: result }
+        Self::from_hex(value)
+    }
+}
+
+/// Implements addition for references to `Fp`, computing (a + b) mod p efficiently.
+/// Avoids unnecessary modular reductions by checking if the sum exceeds p.
+impl<'a, F: PrimeField> Add<&'a Fp<F>> for &Fp<F> {
+    type Output = Fp<F>;
+    fn add(self, rhs: &'a Fp<F>) -> Fp<F> {
+        Fp::from_limbs(limbs::add_mod(&self.limbs, &rhs.limbs, &F::MODULUS))
     }
 }
 
-/// Implements addition for owned `FieldElement` values, delegating to the reference version.
-impl Add for FieldElement {
-    type Output = FieldElement;
-    fn add(self, rhs: FieldElement) -> FieldElement {
+/// Implements addition for owned `Fp` values, delegating to the reference version.
+impl<F: PrimeField> Add for Fp<F> {
+    type Output = Fp<F>;
+    fn add(self, rhs: Fp<F>) -> Fp<F> {
         &self + &rhs
     }
 }
 
-/// Implements subtraction for references to `FieldElement`, computing (a - b) mod p efficiently.
+/// Implements subtraction for references to `Fp`, computing (a - b) mod p efficiently.
 /// Adjusts negative results by adding p to ensure the result is in [0, p-1].
-impl<'a> Sub<&'a FieldElement> for &FieldElement {
-    type Output = FieldElement;
-    fn sub(self, rhs: &'a FieldElement) -> FieldElement {
-        let mut result = &self.num - &rhs.num;
-        if result < BigInt::zero() {
-            result += FieldElement::prime();
-        }
-        FieldElement { num: result }
+impl<'a, F: PrimeField> Sub<&'a Fp<F>> for &Fp<F> {
+    type Output = Fp<F>;
+    fn sub(self, rhs: &'a Fp<F>) -> Fp<F> {
+        Fp::from_limbs(limbs::sub_mod(&self.limbs, &rhs.limbs, &F::MODULUS))
     }
 }
 
-/// Implements subtraction for owned `FieldElement` values, delegating to the reference version.
-impl Sub for FieldElement {
-    type Output = FieldElement;
-    fn sub(self, rhs: FieldElement) -> FieldElement {
+/// Implements subtraction for owned `Fp` values, delegating to the reference version.
+impl<F: PrimeField> Sub for Fp<F> {
+    type Output = Fp<F>;
+    fn sub(self, rhs: Fp<F>) -> Fp<F> {
         &self - &rhs
     }
 }
 
-/// Implements multiplication for references to `FieldElement`, computing (a * b) mod p.
-/// Uses the standard approach of computing the product and then reducing modulo p.
-impl<'a> Mul<&'a FieldElement> for &FieldElement {
-    type Output = FieldElement;
-    fn mul(self, rhs: &'a FieldElement) -> FieldElement {
-        let result = (&self.num * &rhs.num) % FieldElement::prime();
-        FieldElement { num: result }
+/// Implements multiplication for references to `Fp`, computing (a * b) mod p.
+/// Computes the full 512-bit product and then reduces it modulo p with the field's reduction.
+impl<'a, F: PrimeField> Mul<&'a Fp<F>> for &Fp<F> {
+    type Output = Fp<F>;
+    fn mul(self, rhs: &'a Fp<F>) -> Fp<F> {
+        let wide = limbs::mul_wide(&self.limbs, &rhs.limbs);
+        Fp::from_limbs(F::reduce_wide(&wide))
     }
 }
 
-/// Implements multiplication for owned `FieldElement` values, delegating to the reference version.
-impl Mul for FieldElement {
-    type Output = FieldElement;
-    fn mul(self, rhs: FieldElement) -> FieldElement {
+/// Implements multiplication for owned `Fp` values, delegating to the reference version.
+impl<F: PrimeField> Mul for Fp<F> {
+    type Output = Fp<F>;
+    fn mul(self, rhs: Fp<F>) -> Fp<F> {
         &self * &rhs
     }
 }
 
-/// Implements scalar multiplication for `BigInt * FieldElement`, computing (coeff * a) mod p.
-impl Mul<&FieldElement> for BigInt {
-    type Output = FieldElement;
-    fn mul(self, rhs: &FieldElement) -> FieldElement {
-        let num = (self * &rhs.num) % FieldElement::prime();
-        FieldElement { num }
+/// Implements scalar multiplication for `BigInt * Fp`, computing (coeff * a) mod p.
+impl<F: PrimeField> Mul<&Fp<F>> for BigInt {
+    type Output = Fp<F>;
+    fn mul(self, rhs: &Fp<F>) -> Fp<F> {
+        &Fp::<F>::reduce(&self) * rhs
     }
 }
 
-/// Implements division for references to `FieldElement`, computing a / b = a * b^(-1) mod p.
+/// Implements division for references to `Fp`, computing a / b = a * b^(-1) mod p.
 /// Suppresses Clippy warning as the multiplication with inverse is intentional and correct.
 #[allow(clippy::suspicious_arithmetic_impl)]
-impl<'a> Div<&'a FieldElement> for &FieldElement {
-    type Output = FieldElement;
-    fn div(self, rhs: &'a FieldElement) -> FieldElement {
+impl<'a, F: PrimeField> Div<&'a Fp<F>> for &Fp<F> {
+    type Output = Fp<F>;
+    fn div(self, rhs: &'a Fp<F>) -> Fp<F> {
         let rhs_inv = rhs.inverse(); // Compute the inverse (owned value)
         self * &rhs_inv // Multiply reference with reference to inverse
     }
 }
 
-/// Implements division for owned `FieldElement` values, delegating to the reference version.
-impl Div for FieldElement {
-    type Output = FieldElement;
-    fn div(self, rhs: FieldElement) -> FieldElement {
+/// Implements division for owned `Fp` values, delegating to the reference version.
+impl<F: PrimeField> Div for Fp<F> {
+    type Output = Fp<F>;
+    fn div(self, rhs: Fp<F>) -> Fp<F> {
         &self / &rhs
     }
 }
 
-/// Implements the unary negation operator (-) for references to `FieldElement`.
-impl Neg for &FieldElement {
-    type Output = FieldElement;
-    fn neg(self) -> FieldElement {
+/// Implements the unary negation operator (-) for references to `Fp`.
+impl<F: PrimeField> Neg for &Fp<F> {
+    type Output = Fp<F>;
+    fn neg(self) -> Fp<F> {
         self.negate()
     }
 }
 
-/// Implements the unary negation operator (-) for owned `FieldElement` values.
-impl Neg for FieldElement {
-    type Output = FieldElement;
-    fn neg(self) -> FieldElement {
+/// Implements the unary negation operator (-) for owned `Fp` values.
+impl<F: PrimeField> Neg for Fp<F> {
+    type Output = Fp<F>;
+    fn neg(self) -> Fp<F> {
         self.negate()
     }
 }
@@ -1,6 +1,8 @@
-use crate::finite_fields::FieldElement;
-use num_bigint::BigInt;
+use crate::error::Error;
+use crate::finite_fields::{FieldElement, Fp, batch_inverse};
+use num_bigint::{BigInt, Sign};
 use num_traits::{One, Zero};
+use proptest::prelude::*;
 
 //------------------
 // Constructor Tests
@@ -25,9 +27,73 @@ fn test_new_upper_bound() {
 fn test_new_invalid() {
     // Test that creating a FieldElement with the prime p (invalid) returns an error.
     let p = FieldElement::prime();
-    assert!(FieldElement::new(p.clone()).is_err());
+    assert_eq!(FieldElement::new(p.clone()), Err(Error::OutOfRange));
     // Test that creating a FieldElement with a negative number returns an error.
-    assert!(FieldElement::new(BigInt::from(-1)).is_err());
+    assert_eq!(FieldElement::new(BigInt::from(-1)), Err(Error::OutOfRange));
+}
+
+//----------------------------------
+// Byte and String Conversion Tests
+//----------------------------------
+
+#[test]
+fn test_be_bytes_round_trip() {
+    // Test that 32-byte big-endian encoding round-trips and rejects values not below p.
+    let a = FieldElement::new(BigInt::from(0x1234_5678u64)).unwrap();
+    let bytes = a.to_be_bytes();
+    assert_eq!(&bytes[28..], &[0x12, 0x34, 0x56, 0x78]);
+    assert_eq!(FieldElement::from_be_bytes(&bytes).unwrap(), a);
+    assert_eq!(
+        FieldElement::from_be_bytes(&[0xff; 32]),
+        Err(Error::OutOfRange)
+    );
+}
+
+#[test]
+fn test_reduce_be_bytes() {
+    // Test that reduce_be_bytes reduces 2^256 - 1 to 2^256 - 1 - p = 2^32 + 976.
+    let reduced = FieldElement::reduce_be_bytes(&[0xff; 32]);
+    assert_eq!(*reduced.num(), BigInt::from(0x1_0000_03d0u64));
+}
+
+#[test]
+fn test_hex_round_trip() {
+    // Test from_hex with and without the 0x prefix, short inputs and to_hex padding.
+    let a = FieldElement::from_hex("0xff").unwrap();
+    assert_eq!(*a.num(), BigInt::from(255));
+    assert_eq!(FieldElement::from_hex("FF").unwrap(), a);
+    assert_eq!(a.to_hex(), format!("{:0>64}", "ff"));
+    assert_eq!(FieldElement::from_hex(&a.to_hex()).unwrap(), a);
+}
+
+#[test]
+fn test_from_hex_invalid() {
+    // Test that empty, overlong, non-hex and out-of-range inputs are rejected.
+    assert_eq!(FieldElement::from_hex(""), Err(Error::InvalidHex));
+    assert_eq!(
+        FieldElement::from_hex(&"1".repeat(65)),
+        Err(Error::InvalidHex)
+    );
+    assert_eq!(FieldElement::from_hex("0xg1"), Err(Error::InvalidHex));
+    let p_hex = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+    assert_eq!(FieldElement::from_hex(p_hex), Err(Error::OutOfRange));
+}
+
+#[test]
+fn test_from_str_round_trip() {
+    // Test that parsing the Display output gives back the same element.
+    let a = FieldElement::new(BigInt::from(255)).unwrap();
+    assert_eq!(a.to_string().parse::<FieldElement>().unwrap(), a);
+    let b = Fp::<F223>::new(BigInt::from(100)).unwrap();
+    assert_eq!(b.to_string().parse::<Fp<F223>>().unwrap(), b);
+}
+
+#[test]
+fn test_from_str_rejects_other_fields() {
+    // Test that the modulus in the string must match the field being parsed.
+    let a = Fp::<F223>::new(BigInt::from(100)).unwrap();
+    assert_eq!(a.to_string().parse::<Fp<F97>>(), Err(Error::InvalidFormat));
+    assert_eq!("42".parse::<FieldElement>(), Err(Error::InvalidFormat));
 }
 
 //-------------
@@ -339,6 +405,28 @@ fn test_div_by_zero() {
     let _ = &a / &b; // Should panic with "Division by zero"
 }
 
+#[test]
+fn test_checked_div() {
+    // Test that checked_div divides normally and reports division by zero as an error.
+    let a = FieldElement::new(BigInt::from(42)).unwrap();
+    let b = FieldElement::new(BigInt::from(7)).unwrap();
+    assert_eq!(a.checked_div(&b), Ok(&a / &b));
+    assert_eq!(
+        a.checked_div(&FieldElement::zero()),
+        Err(Error::DivisionByZero)
+    );
+}
+
+#[test]
+fn test_error_propagates_as_std_error() {
+    // Test that the crate error converts into Box<dyn std::error::Error> and has a readable message.
+    fn parse() -> Result<FieldElement, Box<dyn std::error::Error>> {
+        Ok(FieldElement::new(BigInt::from(-1))?)
+    }
+    let error = parse().unwrap_err();
+    assert_eq!(error.to_string(), "value is not in the field range");
+}
+
 //-----------------------------------------
 // Scalar Multiplication Tests (coeff * fe)
 //-----------------------------------------
@@ -473,3 +561,278 @@ fn test_double_negation() {
     let double_neg_a_ref = -&neg_a_ref;
     assert_eq!(double_neg_a_ref, a);
 }
+
+//-------------------
+// Small Field Tests
+//-------------------
+
+crate::prime_field!(F31, "1f");
+crate::prime_field!(F57, "39");
+crate::prime_field!(F97, "61");
+crate::prime_field!(F223, "df");
+
+// Helper function to create an element of a small field from a u64
+fn small<F: crate::finite_fields::PrimeField>(n: u64) -> Fp<F> {
+    Fp::new(BigInt::from(n)).unwrap()
+}
+
+#[test]
+fn test_small_field_range() {
+    // Test that the range check uses the field's own modulus.
+    assert_eq!(*Fp::<F223>::prime(), BigInt::from(223));
+    assert!(Fp::<F223>::new(BigInt::from(222)).is_ok());
+    assert!(Fp::<F223>::new(BigInt::from(223)).is_err());
+}
+
+#[test]
+fn test_small_field_add_sub() {
+    // Test the book's F_57 exercises: 44 + 33 = 20, 9 - 29 = 37, 17 + 42 + 49 = 51, 52 - 30 - 38 = 41.
+    assert_eq!(small::<F57>(44) + small(33), small(20));
+    assert_eq!(small::<F57>(9) - small(29), small(37));
+    assert_eq!(small::<F57>(17) + small(42) + small(49), small(51));
+    assert_eq!(small::<F57>(52) - small(30) - small(38), small(41));
+}
+
+#[test]
+fn test_small_field_mul_pow() {
+    // Test the book's F_97 exercises: 95 * 45 * 31 = 23, 17 * 13 * 19 * 44 = 68, 12^7 * 77^49 = 63.
+    assert_eq!(small::<F97>(95) * small(45) * small(31), small(23));
+    assert_eq!(
+        small::<F97>(17) * small(13) * small(19) * small(44),
+        small(68)
+    );
+    let product = small::<F97>(12).pow(BigInt::from(7)) * small::<F97>(77).pow(BigInt::from(49));
+    assert_eq!(product, small(63));
+}
+
+#[test]
+fn test_small_field_div() {
+    // Test the book's F_31 exercises: 3 / 24 = 4, 17^-3 = 29, 4^-4 * 11 = 13.
+    assert_eq!(small::<F31>(3) / small(24), small(4));
+    assert_eq!(small::<F31>(17).pow(BigInt::from(-3)), small(29));
+    assert_eq!(small::<F31>(4).pow(BigInt::from(-4)) * small(11), small(13));
+}
+
+#[test]
+fn test_small_field_neg_wraparound() {
+    // Test that negation and wraparound reduce by the small modulus: -1 = 222 in F_223.
+    assert_eq!(-small::<F223>(1), small(222));
+    assert_eq!(small::<F223>(222) + small(1), Fp::zero());
+}
+
+//------------------------------------
+// Limb Backend Cross-Check Tests
+//------------------------------------
+
+// Helper function turning 32 random bytes into an element together with its BigInt value
+fn element_and_num(bytes: &[u8; 32]) -> (FieldElement, BigInt) {
+    let num = BigInt::from_bytes_be(Sign::Plus, bytes) % FieldElement::prime();
+    (FieldElement::new(num.clone()).unwrap(), num)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn prop_arithmetic_matches_bigint_reference(a in any::<[u8; 32]>(), b in any::<[u8; 32]>()) {
+        // Test that limb-based add, sub and mul agree with plain BigInt arithmetic mod p.
+        let p = FieldElement::prime();
+        let (a, a_num) = element_and_num(&a);
+        let (b, b_num) = element_and_num(&b);
+        let (sum, difference, product) = (&a + &b, &a - &b, &a * &b);
+        prop_assert_eq!(sum.num(), &((&a_num + &b_num) % p));
+        prop_assert_eq!(difference.num(), &(((&a_num - &b_num) % p + p) % p));
+        prop_assert_eq!(product.num(), &((&a_num * &b_num) % p));
+    }
+
+    #[test]
+    fn prop_inverse_matches_bigint_reference(a in any::<[u8; 32]>()) {
+        // Test that the limb-based inverse agrees with BigInt modpow(p - 2).
+        let p = FieldElement::prime();
+        let (a, a_num) = element_and_num(&a);
+        let expected = a_num.modpow(&(p - BigInt::from(2)), p);
+        let inverse = a.pow(BigInt::from(-1));
+        prop_assert_eq!(inverse.num(), &expected);
+    }
+}
+
+#[test]
+fn test_mul_max_values() {
+    // Test the reduction on the largest product: (p - 1) * (p - 1) = 1 mod p.
+    let p_minus_one = FieldElement::new(FieldElement::prime() - BigInt::one()).unwrap();
+    assert_eq!(&p_minus_one * &p_minus_one, FieldElement::one());
+}
+
+//------------------
+// Square Root Tests
+//------------------
+
+crate::prime_field!(F17, "11");
+
+// Exhaustively checks sqrt, is_square and the Legendre symbol over a small field
+fn check_sqrt_exhaustive<F: crate::finite_fields::PrimeField>(p: u64) {
+    let all_squares: Vec<BigInt> = (0..p)
+        .map(|r| small::<F>(r).square().num().clone())
+        .collect();
+    let mut squares = 0;
+    for n in 0..p {
+        let a = small::<F>(n);
+        let is_square = all_squares.contains(a.num());
+        assert_eq!(a.is_square(), is_square, "is_square({}) mod {}", n, p);
+        match a.sqrt() {
+            Some(root) => {
+                assert!(is_square);
+                assert_eq!(&root * &root, a);
+                squares += 1;
+            }
+            None => assert!(!is_square),
+        }
+        let expected_symbol = if n == 0 {
+            0
+        } else if is_square {
+            1
+        } else {
+            -1
+        };
+        assert_eq!(a.legendre_symbol(), expected_symbol);
+    }
+    // Zero plus (p - 1) / 2 non-zero squares
+    assert_eq!(squares, 1 + (p - 1) / 2);
+}
+
+#[test]
+fn test_sqrt_exhaustive_p_3_mod_4() {
+    // Test the (p + 1) / 4 shortcut on every element of F_223 (223 ≡ 3 mod 4).
+    check_sqrt_exhaustive::<F223>(223);
+}
+
+#[test]
+fn test_sqrt_exhaustive_tonelli_shanks() {
+    // Test Tonelli–Shanks on every element of F_17 (16 = 2^4) and F_97 (96 = 3 * 2^5).
+    check_sqrt_exhaustive::<F17>(17);
+    check_sqrt_exhaustive::<F97>(97);
+}
+
+#[test]
+fn test_sqrt_secp256k1_generator() {
+    // Test that sqrt(x^3 + 7) for the generator's x recovers ±y.
+    let x = FieldElement::new(
+        BigInt::parse_bytes(
+            b"79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            16,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let y = FieldElement::new(
+        BigInt::parse_bytes(
+            b"483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+            16,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let seven = FieldElement::new(BigInt::from(7)).unwrap();
+    let alpha = &x.pow(BigInt::from(3)) + &seven;
+    assert!(alpha.is_square());
+    let root = alpha.sqrt().unwrap();
+    assert!(root == y || root == -&y);
+    // The generator's y is even, so the even root must be y itself
+    let even_root = if root.is_even() { root } else { -root };
+    assert_eq!(even_root, y);
+}
+
+#[test]
+fn test_sqrt_secp256k1_non_residue() {
+    // Test that -1 has no square root mod p, since p ≡ 3 (mod 4).
+    let minus_one = -FieldElement::one();
+    assert!(!minus_one.is_square());
+    assert_eq!(minus_one.legendre_symbol(), -1);
+    assert_eq!(minus_one.sqrt(), None);
+}
+
+#[test]
+fn test_sqrt_large_tonelli_shanks() {
+    // Test Tonelli–Shanks on a 256-bit prime: the group order N ≡ 1 (mod 4).
+    let four = crate::scalar::Scalar::new(BigInt::from(4)).unwrap();
+    let root = four.sqrt().unwrap();
+    assert_eq!(&root * &root, four);
+    assert!(*root.num() == BigInt::from(2) || *root.num() == crate::scalar::Scalar::prime() - 2);
+}
+
+#[test]
+fn test_parity() {
+    // Test is_even/is_odd/is_odd_choice on small values and on p - 1 (even).
+    assert!(FieldElement::zero().is_even());
+    assert!(FieldElement::one().is_odd());
+    let p_minus_one = FieldElement::new(FieldElement::prime() - BigInt::one()).unwrap();
+    assert!(p_minus_one.is_even());
+    assert!((-FieldElement::one()).is_even());
+    assert!(bool::from(FieldElement::one().is_odd_choice()));
+    assert!(!bool::from(p_minus_one.is_odd_choice()));
+}
+
+//------------------------
+// Batch Inversion Tests
+//------------------------
+
+#[test]
+fn test_batch_inverse_skips_zeros() {
+    // Test that zeros (first, middle and last) are left untouched while the rest are inverted.
+    let two = FieldElement::new(BigInt::from(2)).unwrap();
+    let three = FieldElement::new(BigInt::from(3)).unwrap();
+    let zero = FieldElement::zero();
+    let mut elements = vec![
+        zero.clone(),
+        two.clone(),
+        zero.clone(),
+        three.clone(),
+        zero.clone(),
+    ];
+    batch_inverse(&mut elements);
+    assert_eq!(
+        elements,
+        vec![
+            zero.clone(),
+            FieldElement::one() / two,
+            zero.clone(),
+            FieldElement::one() / three,
+            zero
+        ]
+    );
+}
+
+#[test]
+fn test_batch_inverse_edge_cases() {
+    // Test the empty slice, an all-zero slice and a single element.
+    let mut empty: Vec<FieldElement> = vec![];
+    batch_inverse(&mut empty);
+    assert!(empty.is_empty());
+
+    let mut zeros = vec![FieldElement::zero(); 3];
+    batch_inverse(&mut zeros);
+    assert_eq!(zeros, vec![FieldElement::zero(); 3]);
+
+    let five = small::<F31>(5);
+    let mut single = vec![five.clone()];
+    batch_inverse(&mut single);
+    assert_eq!(single[0], Fp::one() / five);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn prop_batch_inverse_matches_individual(values in proptest::collection::vec(any::<[u8; 32]>(), 1..10)) {
+        // Test that batch inversion agrees with inverting each element separately.
+        let originals: Vec<FieldElement> = values.iter().map(|bytes| element_and_num(bytes).0).collect();
+        let mut elements = originals.clone();
+        batch_inverse(&mut elements);
+        for (original, inverted) in originals.iter().zip(&elements) {
+            prop_assert_eq!(inverted, &original.pow(BigInt::from(-1)));
+            if !original.is_zero() {
+                prop_assert_eq!(original * inverted, FieldElement::one());
+            }
+        }
+    }
+}
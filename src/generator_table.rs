@@ -0,0 +1,64 @@
+use crate::jacobian::JacobianPoint;
+use crate::point::G;
+use crate::projective::ProjectivePoint;
+use crate::scalar::Scalar;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use once_cell::race::OnceBox;
+use subtle::{Choice, ConstantTimeEq};
+
+// Fixed-base comb for k * G: the scalar is split into 64 windows of 4 bits, and window i
+// contributes digit_i * 16^i * G. All 64 * 16 multiples are precomputed once, so a
+// multiplication is 64 table lookups and additions with no doublings at all.
+const WINDOWS: usize = 64;
+const WINDOW_SIZE: usize = 16;
+
+// GENERATOR_TABLE[i][j] = j * 16^i * G, with entry 0 being the point at infinity.
+// Built on first use; `OnceBox` only needs atomics, so this also works without std.
+static GENERATOR_TABLE: OnceBox<Vec<[ProjectivePoint; WINDOW_SIZE]>> = OnceBox::new();
+
+/// Builds the table in Jacobian coordinates and normalizes all entries with one batch inversion.
+fn build_table() -> Vec<[ProjectivePoint; WINDOW_SIZE]> {
+    let mut multiples = Vec::with_capacity(WINDOWS * (WINDOW_SIZE - 1));
+    let mut base = JacobianPoint::from_affine(&G);
+    for _ in 0..WINDOWS {
+        let base_affine = base.to_affine();
+        let mut current = base.clone();
+        for _ in 1..WINDOW_SIZE {
+            multiples.push(current.clone());
+            current = current.add_mixed(&base_affine);
+        }
+        // The next window's base is 16 times this one, i.e. the value reached after 16 additions
+        base = current;
+    }
+
+    let affine = JacobianPoint::batch_to_affine(&multiples);
+    affine
+        .chunks_exact(WINDOW_SIZE - 1)
+        .map(|window| {
+            core::array::from_fn(|j| match j {
+                0 => ProjectivePoint::identity(),
+                _ => ProjectivePoint::from_affine(&window[j - 1]),
+            })
+        })
+        .collect()
+}
+
+/// Computes k * G using the precomputed table.
+/// Each window reads all 16 entries and keeps the right one with a conditional select, and the
+/// additions use complete formulas, so the running time does not depend on the scalar.
+pub(crate) fn mul_generator(k: &Scalar) -> ProjectivePoint {
+    let limbs = k.limbs();
+    let mut result = ProjectivePoint::identity();
+    let table = GENERATOR_TABLE.get_or_init(|| Box::new(build_table()));
+    for (i, window) in table.iter().enumerate() {
+        let digit = ((limbs[i / 16] >> ((i % 16) * 4)) & 0x0f) as u8;
+        let mut entry = ProjectivePoint::identity();
+        for (j, candidate) in window.iter().enumerate() {
+            let choice: Choice = digit.ct_eq(&(j as u8));
+            entry = ProjectivePoint::conditional_select(&entry, candidate, choice);
+        }
+        result = result.add(&entry);
+    }
+    result
+}
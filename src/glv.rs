@@ -0,0 +1,154 @@
+use crate::finite_fields::FieldElement;
+use crate::jacobian::JacobianPoint;
+use crate::limbs::{self, Limbs};
+use crate::point::Point;
+use crate::scalar::Scalar;
+use alloc::vec::Vec;
+use num_bigint::BigInt;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+// GLV endomorphism (Gallant, Lambert and Vanstone, 2001): since p ≡ 1 (mod 3), the map
+// phi(x, y) = (beta * x, y) with beta a cube root of unity mod p is a group endomorphism of
+// secp256k1, and it acts on every point as multiplication by lambda, a cube root of unity mod N.
+// Writing k = k1 + k2 * lambda (mod N) with |k1|, |k2| < 2^128 turns one 256-bit multiplication
+// into two 128-bit ones that share their doublings, which halves the doublings of the ladder.
+
+// beta^3 = 1 (mod p)
+pub(crate) const BETA: FieldElement = FieldElement::from_limbs(limbs::from_hex(
+    "7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee",
+));
+
+// lambda^3 = 1 (mod N), and lambda * (x, y) = (beta * x, y); only the tests need it as a scalar
+#[cfg(test)]
+pub(crate) const LAMBDA: Scalar = Scalar::from_limbs(limbs::from_hex(
+    "5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72",
+));
+
+// Width of the non-adjacent form: digits are odd and lie in (-2^(w-1), 2^(w-1))
+const WNAF_WIDTH: u32 = 5;
+const TABLE_SIZE: usize = 1 << (WNAF_WIDTH - 2);
+
+// Short basis v1 = (A1, B1), v2 = (A2, B2) of the lattice {(a, b) : a + b * lambda ≡ 0 (mod N)},
+// found with the extended Euclidean algorithm on (N, lambda). B1 is negative, so -B1 is stored.
+const A1: Limbs = limbs::from_hex("3086d221a7d46bcde86c90e49284eb15");
+const MINUS_B1: Limbs = limbs::from_hex("e4437ed6010e88286f547fa90abfe4c3");
+const A2: Limbs = limbs::from_hex("114ca50f7a8e2f3f657c1108d9d44cfd8");
+const B2: Limbs = A1;
+
+/// Applies the endomorphism phi(x, y) = (beta * x, y), which equals lambda * P.
+pub(crate) fn endomorphism(point: &Point) -> Point {
+    match point {
+        Point::Infinity => Point::Infinity,
+        Point::Coordinates { x, y } => Point::Coordinates {
+            x: &BETA * x,
+            y: y.clone(),
+        },
+    }
+}
+
+/// Splits k into signed halves with k ≡ k1 + k2 * lambda (mod N) and |k1|, |k2| < 2^128.
+/// Subtracts from (k, 0) the lattice vector c1 * v1 + c2 * v2 closest to it, where c1 and c2 are
+/// the rounded coordinates of (k, 0) in the basis (v1, v2).
+pub(crate) fn decompose(k: &Scalar) -> (BigInt, BigInt) {
+    let k = k.to_bigint();
+    let n = Scalar::prime();
+    let (a1, minus_b1) = (limbs::to_bigint(&A1), limbs::to_bigint(&MINUS_B1));
+    let (a2, b2) = (limbs::to_bigint(&A2), limbs::to_bigint(&B2));
+    // round(x / N) for x >= 0 is (2x + N) / 2N
+    let round_div = |x: BigInt| -> BigInt { (x * 2 + n) / (n * 2) };
+    let c1 = round_div(&b2 * &k);
+    let c2 = round_div(&minus_b1 * &k);
+    let k1 = k - &c1 * &a1 - &c2 * &a2;
+    let k2 = c1 * &minus_b1 - c2 * &b2;
+    (k1, k2)
+}
+
+/// Returns the width-w non-adjacent form of a non-negative integer, least significant digit first.
+/// Every non-zero digit is odd and followed by at least w - 1 zeros.
+pub(crate) fn wnaf(k: &BigInt, width: u32) -> Vec<i32> {
+    let modulus = 1i32 << width;
+    let mut k = k.clone();
+    let mut digits = Vec::with_capacity(k.bits() as usize + 1);
+    while !k.is_zero() {
+        let mut digit = 0;
+        if k.bit(0) {
+            let low = (&k % modulus).to_i32().unwrap();
+            digit = if low >= modulus / 2 {
+                low - modulus
+            } else {
+                low
+            };
+            k -= digit;
+        }
+        digits.push(digit);
+        k >>= 1;
+    }
+    digits
+}
+
+/// Returns the affine odd multiples P, 3P, ..., (2 * TABLE_SIZE - 1)P, normalized in one batch.
+fn odd_multiples(point: &Point) -> Vec<Point> {
+    let double = JacobianPoint::from_affine(point).double();
+    let mut current = JacobianPoint::from_affine(point);
+    let mut multiples = Vec::with_capacity(TABLE_SIZE);
+    for _ in 0..TABLE_SIZE {
+        multiples.push(current.clone());
+        current = current.add(&double);
+    }
+    JacobianPoint::batch_to_affine(&multiples)
+}
+
+/// Computes k * P as k1 * P + k2 * phi(P) with an interleaved wNAF ladder.
+/// A single table of odd multiples of P serves both halves, since phi maps it onto the odd
+/// multiples of phi(P) at the cost of one field multiplication per entry; negative digits and
+/// negative halves only flip the sign of y. Runs in variable time, like the windowed `Mul`.
+pub(crate) fn mul(point: &Point, k: &Scalar) -> JacobianPoint {
+    if *point == Point::Infinity || k.is_zero() {
+        return JacobianPoint::infinity();
+    }
+
+    let (k1, k2) = decompose(k);
+    let table = odd_multiples(point);
+    let endo_table: Vec<Point> = table.iter().map(endomorphism).collect();
+    let negated = |table: &[Point]| -> Vec<Point> {
+        table
+            .iter()
+            .map(|p| match p {
+                Point::Infinity => Point::Infinity,
+                Point::Coordinates { x, y } => Point::Coordinates {
+                    x: x.clone(),
+                    y: -y,
+                },
+            })
+            .collect()
+    };
+
+    // Each half contributes its wNAF digits, a table for positive digits and one for negative ones
+    let halves = [(k1, table), (k2, endo_table)].map(|(half, table)| {
+        let negative_table = negated(&table);
+        let digits = wnaf(&half.abs(), WNAF_WIDTH);
+        if half.is_negative() {
+            (digits, negative_table, table)
+        } else {
+            (digits, table, negative_table)
+        }
+    });
+
+    let length = halves
+        .iter()
+        .map(|(digits, _, _)| digits.len())
+        .max()
+        .unwrap();
+    let mut result = JacobianPoint::infinity();
+    for i in (0..length).rev() {
+        result = result.double();
+        for (digits, positive, negative) in &halves {
+            match digits.get(i) {
+                Some(&d) if d > 0 => result = result.add_mixed(&positive[d as usize / 2]),
+                Some(&d) if d < 0 => result = result.add_mixed(&negative[(-d) as usize / 2]),
+                _ => {}
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,113 @@
+use crate::finite_fields::FieldElement;
+use crate::glv::{BETA, LAMBDA, decompose, endomorphism, mul, wnaf};
+use crate::point::{G, Point};
+use crate::scalar::Scalar;
+use num_bigint::BigInt;
+use num_traits::{One, Signed};
+use proptest::prelude::*;
+
+//---------------------------
+// Endomorphism Constants
+//---------------------------
+
+#[test]
+fn test_beta_and_lambda_are_cube_roots_of_unity() {
+    // Test that beta^3 = 1 (mod p) and lambda^3 = 1 (mod N), with neither equal to 1.
+    assert_eq!(BETA.pow(BigInt::from(3)), FieldElement::one());
+    assert_ne!(BETA, FieldElement::one());
+    assert_eq!(LAMBDA.pow(BigInt::from(3)), Scalar::one());
+    assert_ne!(LAMBDA, Scalar::one());
+}
+
+#[test]
+fn test_endomorphism_is_multiplication_by_lambda() {
+    // Test that phi(G) = lambda * G.
+    assert_eq!(endomorphism(&G), G.mul_ct(&LAMBDA));
+}
+
+//---------------------------
+// Decomposition Tests
+//---------------------------
+
+#[test]
+fn test_decompose_edge_scalars() {
+    // Test decomposition of 0, 1, lambda and N - 1.
+    for k in [Scalar::zero(), Scalar::one(), LAMBDA, -Scalar::one()] {
+        let (k1, k2) = decompose(&k);
+        let recombined = &Scalar::reduce(&k1) + &(&Scalar::reduce(&k2) * &LAMBDA);
+        assert_eq!(recombined, k);
+    }
+}
+
+//---------------------------
+// Multiplication Cross-Checks
+//---------------------------
+
+#[test]
+fn test_glv_mul_small_scalars() {
+    // Test that GLV multiplication agrees with the windowed algorithm for k = 0..40.
+    for k in 0..40u64 {
+        let k = Scalar::new(BigInt::from(k)).unwrap();
+        assert_eq!(mul(&G, &k).to_affine(), G.mul_windowed(&k).to_affine());
+    }
+}
+
+#[test]
+fn test_glv_mul_special_scalars() {
+    // Test lambda * G, N - 1 and multiplication of the point at infinity.
+    assert_eq!(mul(&G, &LAMBDA).to_affine(), endomorphism(&G));
+    let minus_one = -Scalar::one();
+    assert_eq!(mul(&G, &minus_one).to_affine(), G.mul_ct(&minus_one));
+    assert!(mul(&Point::Infinity, &LAMBDA).is_infinity());
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn prop_glv_mul_matches_windowed(k in any::<[u8; 32]>(), r in any::<[u8; 32]>()) {
+        // Test that GLV multiplication agrees with the windowed algorithm on random points and scalars.
+        let point = Point::mul_generator(&Scalar::reduce_be_bytes(&r));
+        let k = Scalar::reduce_be_bytes(&k);
+        prop_assert_eq!(mul(&point, &k).to_affine(), point.mul_windowed(&k).to_affine());
+        prop_assert_eq!(&point * &k, point.mul_ct(&k));
+    }
+
+    #[test]
+    fn prop_endomorphism_is_multiplication_by_lambda(r in any::<[u8; 32]>()) {
+        // Test that phi(P) = lambda * P for random multiples of the generator.
+        let point = Point::mul_generator(&Scalar::reduce_be_bytes(&r));
+        prop_assert_eq!(endomorphism(&point), point.mul_ct(&LAMBDA));
+    }
+
+    #[test]
+    fn prop_decompose_recombines(k in any::<[u8; 32]>()) {
+        // Test that k1 + k2 * lambda = k (mod N) and both halves stay below 2^128 in magnitude.
+        let k = Scalar::reduce_be_bytes(&k);
+        let bound = BigInt::one() << 128;
+        let (k1, k2) = decompose(&k);
+        prop_assert!(k1.abs() < bound && k2.abs() < bound);
+        let recombined = &Scalar::reduce(&k1) + &(&Scalar::reduce(&k2) * &LAMBDA);
+        prop_assert_eq!(recombined, k);
+    }
+
+    #[test]
+    fn prop_wnaf_digits(k in any::<[u8; 32]>()) {
+        // Test that wNAF digits are odd, bounded, separated by zeros and sum back to the input.
+        let k = Scalar::reduce_be_bytes(&k).num().clone();
+        let digits = wnaf(&k, 5);
+        let mut sum = BigInt::from(0);
+        let mut last_nonzero: Option<usize> = None;
+        for (i, &d) in digits.iter().enumerate() {
+            if d != 0 {
+                prop_assert!(d % 2 != 0 && d.abs() < 16);
+                if let Some(j) = last_nonzero {
+                    prop_assert!(i - j >= 5);
+                }
+                last_nonzero = Some(i);
+            }
+            sum += BigInt::from(d) << i;
+        }
+        prop_assert_eq!(sum, k);
+    }
+}
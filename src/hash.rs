@@ -0,0 +1,30 @@
+// Hash functions used by Bitcoin on top of SHA-256 and RIPEMD-160.
+
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+/// SHA-256 of the input.
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+/// SHA-256 applied twice, the hash of messages, transactions and Base58Check checksums.
+pub(crate) fn hash256(data: &[u8]) -> [u8; 32] {
+    sha256(&sha256(data))
+}
+
+/// RIPEMD-160 of SHA-256, the 20-byte hash of public keys and scripts in addresses.
+pub(crate) fn hash160(data: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(sha256(data)).into()
+}
+
+/// Tagged hash (BIP340) over the concatenation of `parts`: SHA-256 of SHA-256(tag) twice,
+/// then the data, so hashes made for one purpose can never collide with hashes made for another.
+pub(crate) fn tagged_hash(tag: &str, parts: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = sha256(tag.as_bytes());
+    let mut hasher = Sha256::new().chain_update(tag_hash).chain_update(tag_hash);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
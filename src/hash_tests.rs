@@ -0,0 +1,29 @@
+use crate::hash::{hash160, hash256, sha256};
+use crate::point::G;
+
+// Helper function to encode bytes as hex
+fn hex(bytes: &[u8]) -> String {
+    crate::hex::encode(bytes)
+}
+
+#[test]
+fn test_sha256_and_hash256_empty() {
+    // Test the well-known single and double SHA-256 digests of the empty string.
+    assert_eq!(
+        hex(&sha256(b"")),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        hex(&hash256(b"")),
+        "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456"
+    );
+}
+
+#[test]
+fn test_hash160_generator() {
+    // Test hash160 of the compressed generator, the key hash in the BIP173 example address.
+    assert_eq!(
+        hex(&hash160(&G.to_sec(true))),
+        "751e76e8199196d454941c45d1b3a323f1433bd6"
+    );
+}
@@ -0,0 +1,205 @@
+use crate::finite_fields::{FieldElement, batch_inverse};
+use crate::point::Point;
+use alloc::vec::Vec;
+
+/// A secp256k1 point in Jacobian coordinates (X : Y : Z), representing the affine point (X/Z^2, Y/Z^3).
+/// Doubling and mixed addition need no field inversion, so long chains of group operations
+/// only pay for a single inversion when converting back to affine. Z = 0 encodes infinity.
+#[derive(Clone, Debug)]
+pub(crate) struct JacobianPoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+}
+
+impl JacobianPoint {
+    /// Returns the point at infinity (1 : 1 : 0).
+    pub(crate) fn infinity() -> Self {
+        JacobianPoint {
+            x: FieldElement::one(),
+            y: FieldElement::one(),
+            z: FieldElement::zero(),
+        }
+    }
+
+    /// Returns true if this is the point at infinity.
+    pub(crate) fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Lifts an affine point to Jacobian coordinates with Z = 1.
+    pub(crate) fn from_affine(point: &Point) -> Self {
+        match point {
+            Point::Infinity => Self::infinity(),
+            Point::Coordinates { x, y } => JacobianPoint {
+                x: x.clone(),
+                y: y.clone(),
+                z: FieldElement::one(),
+            },
+        }
+    }
+
+    /// Converts back to affine coordinates: x = X / Z^2, y = Y / Z^3 (one inversion).
+    pub(crate) fn to_affine(&self) -> Point {
+        if self.is_infinity() {
+            return Point::Infinity;
+        }
+        self.to_affine_with_z_inv(&self.z.inverse())
+    }
+
+    /// Converts to affine given a precomputed 1 / Z.
+    fn to_affine_with_z_inv(&self, z_inv: &FieldElement) -> Point {
+        let z_inv2 = z_inv.square();
+        let z_inv3 = &z_inv2 * z_inv;
+        Point::Coordinates {
+            x: &self.x * &z_inv2,
+            y: &self.y * &z_inv3,
+        }
+    }
+
+    /// Converts many points to affine with a single shared inversion via `batch_inverse`.
+    pub(crate) fn batch_to_affine(points: &[JacobianPoint]) -> Vec<Point> {
+        let mut z_invs: Vec<FieldElement> = points.iter().map(|p| p.z.clone()).collect();
+        batch_inverse(&mut z_invs);
+        points
+            .iter()
+            .zip(&z_invs)
+            .map(|(point, z_inv)| {
+                if point.is_infinity() {
+                    Point::Infinity
+                } else {
+                    point.to_affine_with_z_inv(z_inv)
+                }
+            })
+            .collect()
+    }
+
+    /// Point doubling for a = 0 (formula "dbl-2009-l"): 2M + 5S, no inversion.
+    pub(crate) fn double(&self) -> Self {
+        if self.is_infinity() || self.y.is_zero() {
+            return Self::infinity();
+        }
+
+        // A = X^2, B = Y^2, C = B^2
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+
+        // D = 2 * ((X + B)^2 - A - C), E = 3 * A, F = E^2
+        let d = &(&(&self.x + &b).square() - &a) - &c;
+        let d = &d + &d;
+        let e = &(&a + &a) + &a;
+        let f = e.square();
+
+        // X3 = F - 2D, Y3 = E * (D - X3) - 8C, Z3 = 2 * Y * Z
+        let x3 = &f - &(&d + &d);
+        let c8 = &c + &c;
+        let c8 = &c8 + &c8;
+        let c8 = &c8 + &c8;
+        let y3 = &(&e * &(&d - &x3)) - &c8;
+        let yz = &self.y * &self.z;
+        let z3 = &yz + &yz;
+
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// General addition of two Jacobian points (formula "add-2007-bl"): 11M + 5S.
+    /// Falls back to doubling when both inputs are the same point.
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        if self.is_infinity() {
+            return other.clone();
+        }
+        if other.is_infinity() {
+            return self.clone();
+        }
+
+        // U1 = X1 * Z2^2, U2 = X2 * Z1^2, S1 = Y1 * Z2^3, S2 = Y2 * Z1^3
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = &self.x * &z2z2;
+        let u2 = &other.x * &z1z1;
+        let s1 = &(&self.y * &other.z) * &z2z2;
+        let s2 = &(&other.y * &self.z) * &z1z1;
+
+        // H = U2 - U1, r = 2 * (S2 - S1)
+        let h = &u2 - &u1;
+        let s2_minus_s1 = &s2 - &s1;
+        if h.is_zero() {
+            return if s2_minus_s1.is_zero() {
+                self.double() // P + P
+            } else {
+                Self::infinity() // P + (-P)
+            };
+        }
+        let r = &s2_minus_s1 + &s2_minus_s1;
+
+        // I = (2H)^2, J = H * I, V = U1 * I
+        let i = (&h + &h).square();
+        let j = &h * &i;
+        let v = &u1 * &i;
+
+        // X3 = r^2 - J - 2V, Y3 = r * (V - X3) - 2 * S1 * J, Z3 = ((Z1 + Z2)^2 - Z1Z1 - Z2Z2) * H
+        let x3 = &(&r.square() - &j) - &(&v + &v);
+        let s1j = &s1 * &j;
+        let y3 = &(&r * &(&v - &x3)) - &(&s1j + &s1j);
+        let z3 = &(&(&(&self.z + &other.z).square() - &z1z1) - &z2z2) * &h;
+
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Mixed addition of a Jacobian point and an affine point (formula "madd-2007-bl"): 7M + 4S.
+    /// Falls back to doubling when both inputs are the same point.
+    pub(crate) fn add_mixed(&self, other: &Point) -> Self {
+        let (x2, y2) = match other {
+            Point::Infinity => return self.clone(),
+            Point::Coordinates { x, y } => (x, y),
+        };
+        if self.is_infinity() {
+            return Self::from_affine(other);
+        }
+
+        // U2 = X2 * Z1^2, S2 = Y2 * Z1^3
+        let z1z1 = self.z.square();
+        let u2 = x2 * &z1z1;
+        let s2 = &(y2 * &self.z) * &z1z1;
+
+        // H = U2 - X1, r = 2 * (S2 - Y1)
+        let h = &u2 - &self.x;
+        let s2_minus_y1 = &s2 - &self.y;
+        if h.is_zero() {
+            return if s2_minus_y1.is_zero() {
+                self.double() // P + P
+            } else {
+                Self::infinity() // P + (-P)
+            };
+        }
+        let r = &s2_minus_y1 + &s2_minus_y1;
+
+        // HH = H^2, I = 4 * HH, J = H * I, V = X1 * I
+        let hh = h.square();
+        let i = &hh + &hh;
+        let i = &i + &i;
+        let j = &h * &i;
+        let v = &self.x * &i;
+
+        // X3 = r^2 - J - 2V, Y3 = r * (V - X3) - 2 * Y1 * J, Z3 = (Z1 + H)^2 - Z1Z1 - HH
+        let x3 = &(&r.square() - &j) - &(&v + &v);
+        let y1j = &self.y * &j;
+        let y3 = &(&r * &(&v - &x3)) - &(&y1j + &y1j);
+        let z3 = &(&(&self.z + &h).square() - &z1z1) - &hh;
+
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
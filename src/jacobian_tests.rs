@@ -0,0 +1,77 @@
+use crate::curve::affine_add;
+use crate::jacobian::JacobianPoint;
+use crate::point::{G, Point};
+use crate::scalar::Scalar;
+use num_bigint::BigInt;
+
+//-----------------------
+// Conversion Tests
+//-----------------------
+
+#[test]
+fn test_affine_round_trip() {
+    // Test that lifting to Jacobian coordinates and converting back is the identity.
+    assert_eq!(JacobianPoint::from_affine(&G).to_affine(), G);
+    assert_eq!(
+        JacobianPoint::from_affine(&Point::Infinity).to_affine(),
+        Point::Infinity
+    );
+}
+
+#[test]
+fn test_batch_to_affine_matches_individual() {
+    // Test that batch normalization agrees with one-by-one conversion, including infinity.
+    let mut points = vec![JacobianPoint::infinity()];
+    let mut current = JacobianPoint::from_affine(&G);
+    for _ in 0..5 {
+        points.push(current.clone());
+        current = current.double();
+    }
+    points.push(JacobianPoint::infinity());
+    let expected: Vec<Point> = points.iter().map(|p| p.to_affine()).collect();
+    assert_eq!(JacobianPoint::batch_to_affine(&points), expected);
+    assert_eq!(expected[0], Point::Infinity);
+}
+
+//-------------------------
+// Group Operation Tests
+//-------------------------
+
+#[test]
+fn test_double_matches_affine_addition() {
+    // Test that Jacobian doubling of 2G agrees with 2G + 2G computed by the affine formulas.
+    let two_g = affine_add(&G, &G);
+    let four_g = JacobianPoint::from_affine(&G).double().double().to_affine();
+    assert_eq!(four_g, affine_add(&two_g, &two_g));
+}
+
+#[test]
+fn test_add_mixed_special_cases() {
+    // Test mixed addition with infinity, with the same point and with the negated point.
+    let g = JacobianPoint::from_affine(&G);
+    let neg_g = Point::new(Some(G.x().clone()), Some(-G.y())).unwrap();
+    assert_eq!(g.add_mixed(&Point::Infinity).to_affine(), G);
+    assert_eq!(JacobianPoint::infinity().add_mixed(&G).to_affine(), G);
+    assert_eq!(g.add_mixed(&G).to_affine(), g.double().to_affine());
+    assert!(g.add_mixed(&neg_g).is_infinity());
+}
+
+#[test]
+fn test_add_mixed_non_normalized() {
+    // Test mixed addition when the Jacobian operand has Z != 1: 2G + G = 3G.
+    let two_g = JacobianPoint::from_affine(&G).double();
+    let three_g = affine_add(&affine_add(&G, &G), &G);
+    assert_eq!(two_g.add_mixed(&G).to_affine(), three_g);
+}
+
+#[test]
+fn test_windowed_mul_matches_repeated_addition() {
+    // Test that the windowed scalar multiplication agrees with repeated addition for k = 1..40,
+    // covering every window digit and carries across nibble boundaries.
+    let mut expected = Point::Infinity;
+    for k in 1..=40u64 {
+        expected = &expected + &G;
+        let k = Scalar::new(BigInt::from(k)).unwrap();
+        assert_eq!(&G * &k, expected);
+    }
+}
@@ -0,0 +1,129 @@
+// Private and public keys for secp256k1.
+// A private key is a secret scalar e in [1, N - 1]; its public key is the point P = e * G,
+// which is never the point at infinity. Both types validate on construction, so code that
+// holds one does not need to check it again.
+// The secret is wiped from memory when a `PrivateKey` is dropped, and `Debug` and `Display`
+// never print it, unlike `Scalar`, whose `Display` shows the value.
+
+use crate::error::Error;
+use crate::point::Point;
+use crate::scalar::Scalar;
+use alloc::vec::Vec;
+use core::fmt;
+use zeroize::Zeroize;
+#[cfg(feature = "std")]
+use zeroize::Zeroizing;
+
+/// A secp256k1 public key: a finite point P = e * G.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    point: Point,
+}
+
+impl PublicKey {
+    /// Wraps a curve point as a public key.
+    /// Returns `Error::PointAtInfinity` for the point at infinity, which has no private key.
+    pub fn from_point(point: Point) -> Result<Self, Error> {
+        if point == Point::Infinity {
+            return Err(Error::PointAtInfinity);
+        }
+        Ok(PublicKey { point })
+    }
+
+    /// Returns the curve point of the key.
+    pub fn point(&self) -> &Point {
+        &self.point
+    }
+
+    /// Serializes the key in SEC1 format: 33 bytes if `compressed`, 65 bytes otherwise.
+    pub fn to_sec(&self, compressed: bool) -> Vec<u8> {
+        self.point.to_sec(compressed)
+    }
+
+    /// Parses a compressed or uncompressed SEC1 public key; see `Point::from_sec`.
+    pub fn from_sec(bytes: &[u8]) -> Result<Self, Error> {
+        Point::from_sec(bytes).map(|point| PublicKey { point })
+    }
+}
+
+/// A secp256k1 private key: a secret scalar e in [1, N - 1], together with its public key e * G.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PrivateKey {
+    secret: Scalar,
+    public_key: PublicKey,
+}
+
+impl PrivateKey {
+    /// Constructs a private key from its secret scalar and derives the public key.
+    /// Returns `Error::OutOfRange` if the secret is zero.
+    pub fn new(secret: Scalar) -> Result<Self, Error> {
+        if secret.is_zero() {
+            return Err(Error::OutOfRange);
+        }
+        let public_key = PublicKey {
+            point: Point::mul_generator(&secret),
+        };
+        Ok(PrivateKey { secret, public_key })
+    }
+
+    /// Constructs a private key from a 32-byte big-endian secret.
+    /// Returns `Error::OutOfRange` unless the value is in [1, N - 1]; it is never reduced.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
+        Self::new(Scalar::from_be_bytes(bytes)?)
+    }
+
+    /// Returns the secret as 32 big-endian bytes.
+    /// The copy is not wiped automatically; callers that keep it should zeroize it.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.secret.to_be_bytes()
+    }
+
+    /// Generates a private key from the operating system's random number generator.
+    /// Draws 32 bytes until they encode a value in [1, N - 1], which almost always takes one draw.
+    /// Returns `Error::RandomSourceUnavailable` if the OS generator fails.
+    #[cfg(feature = "std")]
+    pub fn random() -> Result<Self, Error> {
+        // Wiped on every return, including when the generator fails after a partial fill
+        let mut bytes = Zeroizing::new([0u8; 32]);
+        loop {
+            getrandom::getrandom(bytes.as_mut()).map_err(|_| Error::RandomSourceUnavailable)?;
+            let key = Self::from_bytes(&bytes);
+            if key.is_ok() {
+                return key;
+            }
+        }
+    }
+
+    /// Returns the secret scalar e.
+    pub fn secret(&self) -> &Scalar {
+        &self.secret
+    }
+
+    /// Returns the public key e * G.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+/// Wipes the secret, so it does not linger in freed memory.
+impl Drop for PrivateKey {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+/// Shows only the public key, so keys can be logged without leaking the secret.
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrivateKey")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Formats a `PrivateKey` with the secret redacted.
+impl fmt::Display for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PrivateKey(<redacted>)")
+    }
+}
@@ -0,0 +1,101 @@
+use crate::error::Error;
+use crate::keys::{PrivateKey, PublicKey};
+use crate::point::{G, Point};
+use crate::scalar::Scalar;
+use crate::test_util::bytes;
+use num_bigint::BigInt;
+use zeroize::Zeroize;
+
+//------------------------
+// Construction Tests
+//------------------------
+
+#[test]
+fn test_from_bytes_range() {
+    // Test that secrets 1 and N - 1 are accepted, while 0, N and 2^256 - 1 are rejected.
+    let one = bytes("0000000000000000000000000000000000000000000000000000000000000001");
+    let n_minus_one = bytes("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140");
+    let n = bytes("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+    assert_eq!(
+        PrivateKey::from_bytes(&one).unwrap().public_key().point(),
+        &G
+    );
+    let minus_g = &G * &BigInt::from(-1);
+    let key = PrivateKey::from_bytes(&n_minus_one).unwrap();
+    assert_eq!(key.public_key().point(), &minus_g);
+    assert_eq!(PrivateKey::from_bytes(&[0; 32]), Err(Error::OutOfRange));
+    assert_eq!(PrivateKey::from_bytes(&n), Err(Error::OutOfRange));
+    assert_eq!(PrivateKey::from_bytes(&[0xff; 32]), Err(Error::OutOfRange));
+}
+
+#[test]
+fn test_bytes_round_trip() {
+    // Test that to_bytes returns the secret that from_bytes was given.
+    let secret = bytes("f8b8af8ce3c7cca5e300d33939540c10d45ce001b8f252bfbc57ba0342904181");
+    assert_eq!(PrivateKey::from_bytes(&secret).unwrap().to_bytes(), secret);
+}
+
+#[test]
+fn test_public_key_book_vector() {
+    // Test the book's public key for the secret 5000.
+    let key = PrivateKey::new(Scalar::new(BigInt::from(5000)).unwrap()).unwrap();
+    assert_eq!(
+        key.public_key().point().to_hex(false),
+        "04ffe558e388852f0120e46af2d1b370f85854a8eb0841811ece0e3e03d282d57c315dc72890a4f10a1481c031b03b351b0dc79901ca18a00cf009dbdb157a1d10"
+    );
+}
+
+#[test]
+fn test_public_key_sec_round_trip() {
+    // Test that public keys round-trip through SEC1 and that infinity is rejected.
+    let public_key = PublicKey::from_point(G).unwrap();
+    for compressed in [true, false] {
+        let bytes = public_key.to_sec(compressed);
+        assert_eq!(PublicKey::from_sec(&bytes).unwrap(), public_key);
+    }
+    assert_eq!(PublicKey::from_sec(&[0x00]), Err(Error::PointAtInfinity));
+    assert_eq!(
+        PublicKey::from_point(Point::Infinity),
+        Err(Error::PointAtInfinity)
+    );
+}
+
+//------------------------
+// Secret Handling Tests
+//------------------------
+
+#[test]
+fn test_formatting_redacts_secret() {
+    // Test that neither Debug nor Display reveal the secret, which Scalar's Display would print.
+    let secret = Scalar::from_hex("deadbeef12345").unwrap();
+    assert!(secret.to_string().contains("deadbeef12345"));
+    let limb = format!("{}", 0xdeadbeef12345u64);
+    let key = PrivateKey::new(secret).unwrap();
+    assert_eq!(key.to_string(), "PrivateKey(<redacted>)");
+    let debug = format!("{:?}", key);
+    assert!(debug.starts_with("PrivateKey { public_key: PublicKey"));
+    assert!(!debug.contains(&limb));
+    assert!(!debug.to_lowercase().contains("deadbeef12345"));
+}
+
+#[test]
+fn test_zeroize_scalar() {
+    // Test that zeroizing a scalar sets it to zero, which is how PrivateKey wipes its secret on drop.
+    let mut secret = Scalar::from_hex("deadbeef12345").unwrap();
+    secret.zeroize();
+    assert!(secret.is_zero());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_random_keys() {
+    // Test that OS-random keys are valid, consistent with their public keys, and distinct.
+    let first = PrivateKey::random().unwrap();
+    let second = PrivateKey::random().unwrap();
+    assert_ne!(first, second);
+    for key in [first, second] {
+        let expected = Point::mul_generator(key.secret());
+        assert_eq!(key.public_key().point(), &expected);
+        assert_eq!(PrivateKey::from_bytes(&key.to_bytes()).unwrap(), key);
+    }
+}
@@ -1,8 +1,111 @@
+// The field and curve core only needs `alloc`; the `std` feature (on by default) links the
+// standard library. Tests always build with std.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod address;
+mod base58;
+mod bech32;
+pub mod bip322;
+pub mod curve;
+mod der;
+pub mod ecdsa;
+pub mod error;
 pub mod finite_fields;
+mod generator_table;
+#[cfg(feature = "glv")]
+mod glv;
+mod hash;
+mod hex;
+mod jacobian;
+pub mod keys;
+mod limbs;
+pub mod message;
+mod multi_mul;
 pub mod point;
+mod projective;
+pub mod recovery;
+mod rfc6979;
+pub mod scalar;
+pub mod schnorr;
+mod script;
+pub mod sec;
+#[cfg(feature = "serde")]
+mod serialization;
+mod transaction;
+
+#[cfg(test)]
+mod address_tests;
+
+#[cfg(test)]
+mod base58_tests;
+
+#[cfg(test)]
+mod bech32_tests;
+
+#[cfg(test)]
+mod bip322_tests;
+
+#[cfg(test)]
+mod constant_time_tests;
+
+#[cfg(test)]
+mod curve_tests;
+
+#[cfg(test)]
+mod der_tests;
+
+#[cfg(test)]
+mod ecdsa_tests;
 
 #[cfg(test)]
 mod finite_fields_tests;
 
+#[cfg(all(test, feature = "glv"))]
+mod glv_tests;
+
+#[cfg(test)]
+mod hash_tests;
+
+#[cfg(test)]
+mod jacobian_tests;
+
+#[cfg(test)]
+mod keys_tests;
+
+#[cfg(test)]
+mod message_tests;
+
+#[cfg(test)]
+mod multi_mul_tests;
+
 #[cfg(test)]
 mod point_tests;
+
+#[cfg(test)]
+mod recovery_tests;
+
+#[cfg(test)]
+mod rfc6979_tests;
+
+#[cfg(test)]
+mod scalar_tests;
+
+#[cfg(test)]
+mod schnorr_tests;
+
+#[cfg(test)]
+mod script_tests;
+
+#[cfg(test)]
+mod sec_tests;
+
+#[cfg(all(test, feature = "serde"))]
+mod serialization_tests;
+
+#[cfg(test)]
+mod test_util;
+
+#[cfg(test)]
+mod transaction_tests;
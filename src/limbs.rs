@@ -0,0 +1,245 @@
+// Fixed-width 256-bit arithmetic on four little-endian 64-bit limbs.
+// These helpers back `Fp` and never allocate; values are plain `[u64; 4]` arrays.
+
+use num_bigint::{BigInt, Sign};
+use subtle::{Choice, ConditionallySelectable};
+
+/// A 256-bit unsigned integer as little-endian 64-bit limbs.
+pub(crate) type Limbs = [u64; 4];
+
+/// A 512-bit unsigned integer (e.g., a full product) as little-endian 64-bit limbs.
+pub(crate) type WideLimbs = [u64; 8];
+
+pub(crate) const ZERO: Limbs = [0, 0, 0, 0];
+pub(crate) const ONE: Limbs = [1, 0, 0, 0];
+
+/// Computes a + b + carry, returning (sum, carry).
+#[inline(always)]
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Computes a - b - borrow, returning (difference, borrow) with borrow either 0 or 1.
+#[inline(always)]
+const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub((b as u128) + (borrow as u128));
+    (t as u64, ((t >> 64) as u64) & 1)
+}
+
+/// Computes a + b * c + carry, returning (low word, high word).
+#[inline(always)]
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) * (c as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Adds two 256-bit integers, returning the low 256 bits and the carry out.
+#[inline]
+pub(crate) fn add(a: &Limbs, b: &Limbs) -> (Limbs, u64) {
+    let (r0, c) = adc(a[0], b[0], 0);
+    let (r1, c) = adc(a[1], b[1], c);
+    let (r2, c) = adc(a[2], b[2], c);
+    let (r3, c) = adc(a[3], b[3], c);
+    ([r0, r1, r2, r3], c)
+}
+
+/// Subtracts two 256-bit integers, returning the wrapped difference and the borrow out (0 or 1).
+#[inline]
+pub(crate) fn sub(a: &Limbs, b: &Limbs) -> (Limbs, u64) {
+    let (r0, bw) = sbb(a[0], b[0], 0);
+    let (r1, bw) = sbb(a[1], b[1], bw);
+    let (r2, bw) = sbb(a[2], b[2], bw);
+    let (r3, bw) = sbb(a[3], b[3], bw);
+    ([r0, r1, r2, r3], bw)
+}
+
+/// Returns b if `choice` is set and a otherwise, without branching on `choice`.
+#[inline]
+pub(crate) fn select(a: &Limbs, b: &Limbs, choice: Choice) -> Limbs {
+    [
+        u64::conditional_select(&a[0], &b[0], choice),
+        u64::conditional_select(&a[1], &b[1], choice),
+        u64::conditional_select(&a[2], &b[2], choice),
+        u64::conditional_select(&a[3], &b[3], choice),
+    ]
+}
+
+/// Subtracts p from a if a >= p, without branching on the comparison.
+#[inline]
+fn conditional_sub(a: &Limbs, p: &Limbs) -> Limbs {
+    let (reduced, borrow) = sub(a, p);
+    select(&reduced, a, Choice::from(borrow as u8))
+}
+
+/// Returns true if all limbs are zero.
+#[inline]
+pub(crate) fn is_zero(a: &Limbs) -> bool {
+    (a[0] | a[1] | a[2] | a[3]) == 0
+}
+
+/// Returns bit `i` (0 = least significant) of a 256-bit integer.
+#[inline]
+pub(crate) fn bit(a: &Limbs, i: usize) -> bool {
+    (a[i / 64] >> (i % 64)) & 1 == 1
+}
+
+/// Shifts a 256-bit integer right by `n` bits, for n < 64.
+#[inline]
+pub(crate) fn shr(a: &Limbs, n: u32) -> Limbs {
+    if n == 0 {
+        return *a;
+    }
+    [
+        (a[0] >> n) | (a[1] << (64 - n)),
+        (a[1] >> n) | (a[2] << (64 - n)),
+        (a[2] >> n) | (a[3] << (64 - n)),
+        a[3] >> n,
+    ]
+}
+
+/// Computes (a + b) mod p for a, b < p.
+#[inline]
+pub(crate) fn add_mod(a: &Limbs, b: &Limbs, p: &Limbs) -> Limbs {
+    let (sum, carry) = add(a, b);
+    let (reduced, borrow) = sub(&sum, p);
+    // Keep the unreduced sum only if it did not overflow and was already below p
+    select(&reduced, &sum, Choice::from((borrow & !carry & 1) as u8))
+}
+
+/// Computes (a - b) mod p for a, b < p.
+#[inline]
+pub(crate) fn sub_mod(a: &Limbs, b: &Limbs, p: &Limbs) -> Limbs {
+    let (diff, borrow) = sub(a, b);
+    let mask = borrow.wrapping_neg();
+    add(&diff, &[p[0] & mask, p[1] & mask, p[2] & mask, p[3] & mask]).0
+}
+
+/// Computes the full 512-bit product of two 256-bit integers (schoolbook multiplication).
+#[inline]
+pub(crate) fn mul_wide(a: &Limbs, b: &Limbs) -> WideLimbs {
+    let mut r = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0;
+        for j in 0..4 {
+            let (lo, hi) = mac(r[i + j], a[i], b[j], carry);
+            r[i + j] = lo;
+            carry = hi;
+        }
+        r[i + 4] = carry;
+    }
+    r
+}
+
+/// Reduces a 512-bit integer modulo a special-form prime p = 2^256 - c with c < 2^130.
+/// Uses 2^256 ≡ c (mod p) to fold the high half onto the low half; four folds always suffice
+/// for such c, so the loop runs a fixed number of times regardless of the value.
+/// Used for the secp256k1 group order (c < 2^129).
+pub(crate) fn reduce_wide_special(wide: &WideLimbs, c: &Limbs, p: &Limbs) -> Limbs {
+    let mut value = *wide;
+    for _ in 0..4 {
+        let product = mul_wide(&[value[4], value[5], value[6], value[7]], c);
+        let mut carry = 0;
+        for i in 0..8 {
+            let low = if i < 4 { value[i] } else { 0 };
+            let (sum, c) = adc(low, product[i], carry);
+            value[i] = sum;
+            carry = c;
+        }
+    }
+    conditional_sub(&[value[0], value[1], value[2], value[3]], p)
+}
+
+/// Reduces a 512-bit integer modulo p = 2^256 - c where c fits in a single limb.
+/// Same folding idea as `reduce_wide_special`, unrolled: two folds, a final carry fold and one subtraction.
+pub(crate) fn reduce_wide_special_limb(wide: &WideLimbs, c: u64, p: &Limbs) -> Limbs {
+    // First fold: lo + hi * c fits in five limbs
+    let (r0, carry) = mac(wide[0], wide[4], c, 0);
+    let (r1, carry) = mac(wide[1], wide[5], c, carry);
+    let (r2, carry) = mac(wide[2], wide[6], c, carry);
+    let (r3, r4) = mac(wide[3], wide[7], c, carry);
+
+    // Second fold: the fifth limb times c, leaving at most a single carry bit
+    let (r0, carry) = mac(r0, r4, c, 0);
+    let (r1, carry) = adc(r1, 0, carry);
+    let (r2, carry) = adc(r2, 0, carry);
+    let (r3, carry) = adc(r3, 0, carry);
+
+    // Final fold of the carry bit cannot overflow again, since the low limbs are now tiny
+    let (r0, carry) = mac(r0, carry, c, 0);
+    let (r1, carry) = adc(r1, 0, carry);
+    let (r2, carry) = adc(r2, 0, carry);
+    let (r3, _) = adc(r3, 0, carry);
+
+    conditional_sub(&[r0, r1, r2, r3], p)
+}
+
+/// Reduces a 512-bit integer modulo an arbitrary p < 2^256 by binary long division.
+/// Slow but general; used for fields without a specialized reduction, such as small test fields.
+pub(crate) fn reduce_wide_generic(wide: &WideLimbs, p: &Limbs) -> Limbs {
+    let mut r = ZERO;
+    for i in (0..512).rev() {
+        let incoming = (wide[i / 64] >> (i % 64)) & 1;
+        let top = r[3] >> 63;
+        r = [
+            (r[0] << 1) | incoming,
+            (r[1] << 1) | (r[0] >> 63),
+            (r[2] << 1) | (r[1] >> 63),
+            (r[3] << 1) | (r[2] >> 63),
+        ];
+        let (reduced, borrow) = sub(&r, p);
+        r = select(&reduced, &r, Choice::from((borrow & !top & 1) as u8));
+    }
+    r
+}
+
+/// Parses a big-endian hex string of at most 64 digits into limbs at compile time.
+/// Panics (a compile error in const context) on invalid digits or overlong input.
+pub(crate) const fn from_hex(hex: &str) -> Limbs {
+    let bytes = hex.as_bytes();
+    assert!(bytes.len() <= 64, "hex value exceeds 256 bits");
+    let mut limbs = ZERO;
+    let mut i = 0;
+    while i < bytes.len() {
+        let digit = match bytes[bytes.len() - 1 - i] {
+            b @ b'0'..=b'9' => b - b'0',
+            b @ b'a'..=b'f' => b - b'a' + 10,
+            b @ b'A'..=b'F' => b - b'A' + 10,
+            _ => panic!("invalid hex digit"),
+        };
+        limbs[i / 16] |= (digit as u64) << ((i % 16) * 4);
+        i += 1;
+    }
+    limbs
+}
+
+/// Converts 32 big-endian bytes into limbs.
+pub(crate) fn from_be_bytes(bytes: &[u8; 32]) -> Limbs {
+    let mut limbs = ZERO;
+    for (i, chunk) in bytes.chunks_exact(8).enumerate() {
+        limbs[3 - i] = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+/// Converts limbs into 32 big-endian bytes.
+pub(crate) fn to_be_bytes(limbs: &Limbs) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, chunk) in bytes.chunks_exact_mut(8).enumerate() {
+        chunk.copy_from_slice(&limbs[3 - i].to_be_bytes());
+    }
+    bytes
+}
+
+/// Converts limbs into a non-negative `BigInt`.
+pub(crate) fn to_bigint(limbs: &Limbs) -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, &to_be_bytes(limbs))
+}
+
+/// Converts a `BigInt` in the range [0, 2^256) into limbs.
+pub(crate) fn from_bigint(num: &BigInt) -> Limbs {
+    let (_, digits) = num.to_bytes_be();
+    let mut bytes = [0u8; 32];
+    bytes[32 - digits.len()..].copy_from_slice(&digits);
+    from_be_bytes(&bytes)
+}
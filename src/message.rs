@@ -0,0 +1,124 @@
+// Signed messages in the format of Bitcoin Core's `signmessage`, with the BIP137 header bytes
+// for segwit addresses.
+// The digest is hash256 of "\x18Bitcoin Signed Message:\n" followed by the message, each
+// preceded by its length as a compact-size integer (the prefix above already includes its own
+// length byte, 0x18). The signature is the 65-byte recoverable compact form in Base64, whose
+// header byte tells the verifier which address the recovered key should produce:
+//
+//   27-30  P2PKH, uncompressed key     35-38  P2SH-P2WPKH
+//   31-34  P2PKH, compressed key       39-42  P2WPKH
+//
+// Each header range belongs to one address type, so a header only verifies against an address
+// of that type. The one exception is Electrum, which signs segwit addresses with the P2PKH
+// compressed headers; those are also accepted for P2SH-P2WPKH and P2WPKH addresses.
+
+use crate::address::Address;
+use crate::error::Error;
+use crate::hash::hash256;
+use crate::keys::PrivateKey;
+use crate::recovery::RecoverableSignature;
+use crate::scalar::Scalar;
+use crate::transaction::write_compact_size;
+use alloc::string::String;
+use alloc::vec::Vec;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+const MESSAGE_MAGIC: &[u8] = b"\x18Bitcoin Signed Message:\n";
+
+/// The address type a message signature is made for, which selects its header byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageAddressType {
+    /// P2PKH with an uncompressed key (headers 27 to 30).
+    P2pkhUncompressed,
+    /// P2PKH with a compressed key (headers 31 to 34).
+    P2pkhCompressed,
+    /// P2SH-wrapped P2WPKH (headers 35 to 38).
+    P2shP2wpkh,
+    /// Native P2WPKH (headers 39 to 42).
+    P2wpkh,
+}
+
+impl MessageAddressType {
+    /// Header byte for recovery id 0.
+    fn header_base(self) -> u8 {
+        match self {
+            MessageAddressType::P2pkhUncompressed => 27,
+            MessageAddressType::P2pkhCompressed => 31,
+            MessageAddressType::P2shP2wpkh => 35,
+            MessageAddressType::P2wpkh => 39,
+        }
+    }
+}
+
+/// Computes the digest signed for a message: hash256 of the magic prefix and the
+/// length-prefixed message.
+pub fn message_hash(message: &[u8]) -> [u8; 32] {
+    let mut bytes = Vec::with_capacity(MESSAGE_MAGIC.len() + 9 + message.len());
+    bytes.extend_from_slice(MESSAGE_MAGIC);
+    write_compact_size(&mut bytes, message.len() as u64);
+    bytes.extend_from_slice(message);
+    hash256(&bytes)
+}
+
+impl PrivateKey {
+    /// Signs a message for the given address type and returns the Base64 signature.
+    /// The signature is deterministic (RFC 6979), so it matches Bitcoin Core and Electrum.
+    pub fn sign_message(&self, message: &str, address_type: MessageAddressType) -> String {
+        let z = Scalar::reduce_be_bytes(&message_hash(message.as_bytes()));
+        let signature = self.sign_recoverable(&z);
+        let mut compact = signature.to_compact(true);
+        compact[0] = address_type.header_base() + signature.recovery_id();
+        BASE64.encode(compact)
+    }
+}
+
+/// Verifies a Base64 message signature against a P2PKH, P2SH-P2WPKH or P2WPKH address.
+/// Returns `Ok(false)` if the signature is well formed but was not made by the address's key.
+/// Returns the address parsing errors of `Address::from_str`, `Error::UnsupportedAddress` for
+/// P2WSH and P2TR addresses, which have no single key to recover, `Error::InvalidBase64` if the
+/// signature is not Base64, `Error::InvalidLength` unless it decodes to 65 bytes,
+/// `Error::InvalidPrefix` for a header outside 27..=42 and `Error::OutOfRange` if r or s is
+/// zero or not below N.
+pub fn verify_message(address: &str, signature: &str, message: &str) -> Result<bool, Error> {
+    let address: Address = address.parse()?;
+    if matches!(address, Address::P2wsh { .. } | Address::P2tr { .. }) {
+        return Err(Error::UnsupportedAddress);
+    }
+    let bytes = BASE64.decode(signature).map_err(|_| Error::InvalidBase64)?;
+    let mut compact: [u8; 65] = bytes
+        .try_into()
+        .map_err(|bytes: Vec<u8>| Error::InvalidLength(bytes.len()))?;
+    let header = compact[0];
+    if !(27..=42).contains(&header) {
+        return Err(Error::InvalidPrefix(header));
+    }
+    // Parse the BIP137 segwit headers as the P2PKH compressed ones, which carry the same key
+    if header >= 35 {
+        compact[0] = 31 + (header - 27) % 4;
+    }
+    let (signature, compressed) = RecoverableSignature::from_compact(&compact)?;
+
+    // Segwit outputs can only be spent with compressed keys, so 27 to 30 are P2PKH only
+    let header_matches = match address {
+        Address::P2pkh { .. } => header <= 34,
+        Address::P2sh { .. } => (31..=38).contains(&header),
+        Address::P2wpkh { .. } => (31..=34).contains(&header) || header >= 39,
+        Address::P2wsh { .. } | Address::P2tr { .. } => false,
+    };
+    if !header_matches {
+        return Ok(false);
+    }
+    let z = Scalar::reduce_be_bytes(&message_hash(message.as_bytes()));
+    let public_key = match signature.recover(&z) {
+        Ok(public_key) => public_key,
+        Err(_) => return Ok(false),
+    };
+    let network = address.network();
+    let expected = match address {
+        Address::P2pkh { .. } => Address::p2pkh(&public_key, compressed, network),
+        Address::P2sh { .. } => Address::p2sh_p2wpkh(&public_key, network),
+        _ => Address::p2wpkh(&public_key, network),
+    };
+    Ok(expected == address)
+}
@@ -0,0 +1,212 @@
+use crate::error::Error;
+use crate::message::{MessageAddressType, message_hash, verify_message};
+use crate::test_util::{bip322_key, key};
+
+// Signatures of "Hello World" by the BIP322 key for each address type, with its address
+const HELLO_WORLD: [(MessageAddressType, &str, &str); 4] = [
+    (
+        MessageAddressType::P2pkhUncompressed,
+        "169ojqRJ3d4f7aNMu86nAAwGJyeykmByFU",
+        "HOW2xi+ebJLeBtr674l4QH76dqDoVjLV80R9EFKFQX5rBrlCXPIZaYs8Yuayg0ZqjyiCbLy9pzZIS7JWT65/nsU=",
+    ),
+    (
+        MessageAddressType::P2pkhCompressed,
+        "14vV3aCHBeStb5bkenkNHbe2YAFinYdXgc",
+        "IOW2xi+ebJLeBtr674l4QH76dqDoVjLV80R9EFKFQX5rBrlCXPIZaYs8Yuayg0ZqjyiCbLy9pzZIS7JWT65/nsU=",
+    ),
+    (
+        MessageAddressType::P2shP2wpkh,
+        "37qyp7jQAzqb2rCBpMvVtLDuuzKAUCVnJb",
+        "JOW2xi+ebJLeBtr674l4QH76dqDoVjLV80R9EFKFQX5rBrlCXPIZaYs8Yuayg0ZqjyiCbLy9pzZIS7JWT65/nsU=",
+    ),
+    (
+        MessageAddressType::P2wpkh,
+        "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l",
+        "KOW2xi+ebJLeBtr674l4QH76dqDoVjLV80R9EFKFQX5rBrlCXPIZaYs8Yuayg0ZqjyiCbLy9pzZIS7JWT65/nsU=",
+    ),
+];
+
+//------------------------------
+// Digest Tests
+//------------------------------
+
+#[test]
+fn test_message_hash() {
+    // Test the signed-message digests of the empty message and "Hello World".
+    assert_eq!(
+        crate::hex::encode(&message_hash(b"")),
+        "80e795d4a4caadd7047af389d9f7f220562feb6196032e2131e10563352c4bcc"
+    );
+    assert_eq!(
+        crate::hex::encode(&message_hash(b"Hello World")),
+        "a7af0baad5ae99b97fc69b3a0d1abcf3ef17f131cc4776e1bc11933ec8550f49"
+    );
+}
+
+#[test]
+fn test_message_hash_long_length_prefix() {
+    // Test that messages of 253 bytes or more get the three-byte 0xfd length prefix.
+    let message = [b'a'; 300];
+    let mut serialized = b"\x18Bitcoin Signed Message:\n".to_vec();
+    serialized.extend_from_slice(&[0xfd, 0x2c, 0x01]);
+    serialized.extend_from_slice(&message);
+    assert_eq!(message_hash(&message), crate::hash::hash256(&serialized));
+}
+
+//------------------------------
+// Signing Vector Tests
+//------------------------------
+
+#[test]
+fn test_bitcoin_core_vector() {
+    // Test the signature from Bitcoin Core's rpc_signmessage functional test, on testnet.
+    let key = key("d2b8a0116d641fe7d3036f8464628fb595b480414c13a301b3d4038c811c28b0");
+    let message = "This is just a test message";
+    let signature =
+        "INbVnW4e6PeRmsv2Qgu8NuopvrVjkcxob+sX8OcZG0SALhWybUjzMLPdAsXI46YZGb0KQTRii+wWIQzRpG/U+S0=";
+    assert_eq!(
+        key.sign_message(message, MessageAddressType::P2pkhCompressed),
+        signature
+    );
+    let address = "mpLQjfK79b7CCV4VMJWEWAj5Mpx8Up5zxB";
+    assert_eq!(verify_message(address, signature, message), Ok(true));
+    assert_eq!(
+        verify_message(address, signature, "Other message"),
+        Ok(false)
+    );
+}
+
+#[test]
+fn test_bip137_header_vectors() {
+    // Test that each address type gets its BIP137 header and verifies against its address.
+    let key = bip322_key();
+    for (address_type, address, signature) in HELLO_WORLD {
+        assert_eq!(key.sign_message("Hello World", address_type), signature);
+        assert_eq!(
+            verify_message(address, signature, "Hello World"),
+            Ok(true),
+            "{}",
+            address
+        );
+    }
+}
+
+//------------------------------
+// Verification Tests
+//------------------------------
+
+#[test]
+fn test_verify_electrum_segwit_headers() {
+    // Test that segwit addresses accept the compressed P2PKH header Electrum signs them with,
+    // but never a signature for an uncompressed key.
+    let (_, _, compressed) = HELLO_WORLD[1];
+    let (_, _, uncompressed) = HELLO_WORLD[0];
+    for (_, address, _) in &HELLO_WORLD[2..] {
+        assert_eq!(verify_message(address, compressed, "Hello World"), Ok(true));
+        assert_eq!(
+            verify_message(address, uncompressed, "Hello World"),
+            Ok(false)
+        );
+    }
+}
+
+#[test]
+fn test_verify_wrong_key_or_compression() {
+    // Test that a P2PKH address only matches the key form its header claims.
+    let (_, uncompressed_address, uncompressed) = HELLO_WORLD[0];
+    let (_, compressed_address, compressed) = HELLO_WORLD[1];
+    assert_eq!(
+        verify_message(uncompressed_address, compressed, "Hello World"),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_message(compressed_address, uncompressed, "Hello World"),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_message(
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+            compressed,
+            "Hello World"
+        ),
+        Ok(false)
+    );
+}
+
+#[test]
+fn test_verify_segwit_headers_need_their_address_type() {
+    // Test that a BIP137 segwit header only verifies against its own address type, even
+    // though the key behind it also owns the other addresses.
+    let (_, p2pkh_address, _) = HELLO_WORLD[1];
+    let (_, p2sh_address, p2sh_signature) = HELLO_WORLD[2];
+    let (_, p2wpkh_address, p2wpkh_signature) = HELLO_WORLD[3];
+    assert_eq!(
+        verify_message(p2pkh_address, p2wpkh_signature, "Hello World"),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_message(p2pkh_address, p2sh_signature, "Hello World"),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_message(p2sh_address, p2wpkh_signature, "Hello World"),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_message(p2wpkh_address, p2sh_signature, "Hello World"),
+        Ok(false)
+    );
+}
+
+#[test]
+fn test_verify_unsupported_addresses() {
+    // Test that P2WSH and P2TR addresses are unsupported whatever the signature bytes are.
+    let (_, _, signature) = HELLO_WORLD[1];
+    for address in [
+        "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3",
+        "bc1ppv609nr0vr25u07u95waq5lucwfm6tde4nydujnu8npg4q75mr5sxq8lt3",
+    ] {
+        assert_eq!(
+            verify_message(address, signature, "Hello World"),
+            Err(Error::UnsupportedAddress)
+        );
+        assert_eq!(
+            verify_message(address, "not base64!", "Hello World"),
+            Err(Error::UnsupportedAddress)
+        );
+    }
+}
+
+#[test]
+fn test_verify_malformed_signatures() {
+    // Test that undecodable signatures and unknown headers are errors rather than mismatches.
+    let address = "14vV3aCHBeStb5bkenkNHbe2YAFinYdXgc";
+    let (_, _, signature) = HELLO_WORLD[1];
+    assert_eq!(
+        verify_message(address, "not base64!", "Hello World"),
+        Err(Error::InvalidBase64)
+    );
+    assert_eq!(
+        verify_message(address, &signature[..84], "Hello World"),
+        Err(Error::InvalidLength(63))
+    );
+    let mut bytes = [0u8; 65];
+    bytes[0] = 43;
+    bytes[32] = 1;
+    bytes[64] = 1;
+    let header_43 = base64_encode(&bytes);
+    assert_eq!(
+        verify_message(address, &header_43, "Hello World"),
+        Err(Error::InvalidPrefix(43))
+    );
+    assert_eq!(
+        verify_message("not an address", signature, "Hello World"),
+        Err(Error::InvalidBase58)
+    );
+}
+
+// Helper function to encode bytes in standard Base64
+fn base64_encode(bytes: &[u8]) -> String {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
@@ -0,0 +1,93 @@
+use crate::jacobian::JacobianPoint;
+use crate::point::Point;
+use crate::scalar::Scalar;
+use alloc::vec;
+use alloc::vec::Vec;
+
+// Above this many terms Pippenger's bucket method beats Strauss–Shamir interleaving.
+const PIPPENGER_THRESHOLD: usize = 64;
+
+/// Computes the sum of k_i * P_i, choosing the algorithm by the number of terms.
+pub(crate) fn multi_mul(terms: &[(Scalar, Point)]) -> JacobianPoint {
+    if terms.len() < PIPPENGER_THRESHOLD {
+        strauss(terms)
+    } else {
+        pippenger(terms)
+    }
+}
+
+/// Returns the `index`-th window of `width` bits of a scalar (window 0 is least significant).
+fn window(k: &Scalar, index: usize, width: usize) -> usize {
+    let limbs = k.limbs();
+    let start = index * width;
+    let mut digit = 0;
+    for bit in (start..(start + width).min(256)).rev() {
+        digit = (digit << 1) | ((limbs[bit / 64] >> (bit % 64)) & 1) as usize;
+    }
+    digit
+}
+
+/// Strauss–Shamir interleaving with 4-bit windows: every point gets a table of 1P..15P
+/// (all normalized with one batch inversion), and the 256 doublings are shared by all terms.
+pub(crate) fn strauss(terms: &[(Scalar, Point)]) -> JacobianPoint {
+    let mut multiples = Vec::with_capacity(terms.len() * 15);
+    for (_, point) in terms {
+        let mut current = JacobianPoint::from_affine(point);
+        for _ in 0..15 {
+            multiples.push(current.clone());
+            current = current.add_mixed(point);
+        }
+    }
+    let tables = JacobianPoint::batch_to_affine(&multiples);
+
+    let mut result = JacobianPoint::infinity();
+    for index in (0..64).rev() {
+        for _ in 0..4 {
+            result = result.double();
+        }
+        for ((k, _), table) in terms.iter().zip(tables.chunks_exact(15)) {
+            let digit = window(k, index, 4);
+            if digit != 0 {
+                result = result.add_mixed(&table[digit - 1]);
+            }
+        }
+    }
+    result
+}
+
+/// Pippenger's bucket method: for each c-bit window, every point is added once into the bucket
+/// of its digit, and the buckets are combined as sum(d * B_d) with two running sums.
+/// Costs roughly (256 / c) * (n + 2^(c+1)) additions, which is sublinear per term for large n.
+pub(crate) fn pippenger(terms: &[(Scalar, Point)]) -> JacobianPoint {
+    let width = match terms.len() {
+        0..=31 => 3,
+        // ln(n) + 2, with ln(n) approximated as 0.7 * log2(n) to stay in integer arithmetic
+        n => n.ilog2() as usize * 7 / 10 + 2,
+    };
+    let windows = 256usize.div_ceil(width);
+
+    let mut result = JacobianPoint::infinity();
+    for index in (0..windows).rev() {
+        for _ in 0..width {
+            result = result.double();
+        }
+
+        let mut buckets = vec![JacobianPoint::infinity(); (1 << width) - 1];
+        for (k, point) in terms {
+            let digit = window(k, index, width);
+            if digit != 0 {
+                buckets[digit - 1] = buckets[digit - 1].add_mixed(point);
+            }
+        }
+
+        // running = B_d + ... + B_max, accumulated once per d, gives sum(d * B_d)
+        let mut running = JacobianPoint::infinity();
+        let mut window_sum = JacobianPoint::infinity();
+        for bucket in buckets.iter().rev() {
+            running = running.add(bucket);
+            window_sum = window_sum.add(&running);
+        }
+        result = result.add(&window_sum);
+    }
+    result
+}
@@ -0,0 +1,106 @@
+use crate::multi_mul::{pippenger, strauss};
+use crate::point::{G, Point};
+use crate::scalar::Scalar;
+use num_bigint::BigInt;
+use proptest::prelude::*;
+
+// Computes the sum of k_i * P_i one product at a time, as the reference result
+fn naive_sum(terms: &[(Scalar, Point)]) -> Point {
+    terms
+        .iter()
+        .fold(Point::Infinity, |acc, (k, p)| &acc + &(p * k))
+}
+
+// Builds terms from pairs of 32-byte strings: scalar k and point r * G
+fn terms_from_bytes(seeds: &[([u8; 32], [u8; 32])]) -> Vec<(Scalar, Point)> {
+    seeds
+        .iter()
+        .map(|(k, r)| {
+            let point = Point::mul_generator(&Scalar::reduce_be_bytes(r));
+            (Scalar::reduce_be_bytes(k), point)
+        })
+        .collect()
+}
+
+// Helper function to create a Scalar from a small integer
+fn scalar(n: i64) -> Scalar {
+    Scalar::reduce(&BigInt::from(n))
+}
+
+//-------------------
+// Edge Case Tests
+//-------------------
+
+#[test]
+fn test_multi_mul_empty() {
+    // Test that the empty sum is the point at infinity.
+    assert_eq!(Point::multi_mul(&[]), Point::Infinity);
+    assert!(pippenger(&[]).is_infinity());
+}
+
+#[test]
+fn test_multi_mul_zero_scalars_and_infinity() {
+    // Test that zero scalars and infinity points contribute nothing.
+    let terms = vec![
+        (scalar(0), G.clone()),
+        (scalar(5), Point::Infinity),
+        (scalar(3), G.clone()),
+    ];
+    let expected = &G * &scalar(3);
+    assert_eq!(Point::multi_mul(&terms), expected);
+    assert_eq!(pippenger(&terms).to_affine(), expected);
+}
+
+#[test]
+fn test_multi_mul_cancellation() {
+    // Test that k * G + (-k) * G = ∞ and that repeated points are summed: 2G + 3G = 5G.
+    let cancelling = vec![(scalar(7), G.clone()), (scalar(-7), G.clone())];
+    assert_eq!(Point::multi_mul(&cancelling), Point::Infinity);
+    assert!(pippenger(&cancelling).is_infinity());
+
+    let repeated = vec![(scalar(2), G.clone()), (scalar(3), G.clone())];
+    assert_eq!(Point::multi_mul(&repeated), &G * &scalar(5));
+}
+
+#[test]
+fn test_multi_mul_large_batch_uses_pippenger() {
+    // Test a batch above the Pippenger threshold against the naive sum.
+    let seeds: Vec<([u8; 32], [u8; 32])> = (0..70u8)
+        .map(|i| ([i.wrapping_mul(37); 32], [i.wrapping_add(1); 32]))
+        .collect();
+    let terms = terms_from_bytes(&seeds);
+    assert_eq!(Point::multi_mul(&terms), naive_sum(&terms));
+}
+
+//-------------------
+// Property Tests
+//-------------------
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn prop_strauss_matches_naive(seeds in prop::collection::vec(any::<([u8; 32], [u8; 32])>(), 0..6)) {
+        // Test that Strauss–Shamir interleaving agrees with the naive sum.
+        let terms = terms_from_bytes(&seeds);
+        prop_assert_eq!(strauss(&terms).to_affine(), naive_sum(&terms));
+    }
+
+    #[test]
+    fn prop_pippenger_matches_naive(seeds in prop::collection::vec(any::<([u8; 32], [u8; 32])>(), 0..12)) {
+        // Test that Pippenger's bucket method agrees with the naive sum.
+        let terms = terms_from_bytes(&seeds);
+        prop_assert_eq!(pippenger(&terms).to_affine(), naive_sum(&terms));
+    }
+
+    #[test]
+    fn prop_multi_mul_ecdsa_shape(u1 in any::<[u8; 32]>(), u2 in any::<[u8; 32]>(), d in any::<[u8; 32]>()) {
+        // Test the two-term u1 * G + u2 * Q used by ECDSA verification.
+        let q = Point::mul_generator(&Scalar::reduce_be_bytes(&d));
+        let terms = vec![
+            (Scalar::reduce_be_bytes(&u1), G.clone()),
+            (Scalar::reduce_be_bytes(&u2), q),
+        ];
+        prop_assert_eq!(Point::multi_mul(&terms), naive_sum(&terms));
+    }
+}
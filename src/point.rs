@@ -1,191 +1,142 @@
-use crate::finite_fields::FieldElement;
-use num_bigint::BigInt;
-use num_traits::{One, Zero};
-use std::fmt;
-use std::ops::{Add, Mul};
+use crate::curve::{Curve, CurvePoint};
+use crate::finite_fields::{FieldElement, Secp256k1Field, modulus_limbs};
+use crate::generator_table;
+#[cfg(feature = "glv")]
+use crate::glv;
+use crate::jacobian::JacobianPoint;
+use crate::multi_mul;
+use crate::projective::ProjectivePoint;
+use crate::scalar::{Scalar, Secp256k1Order};
+use alloc::vec::Vec;
 
 // Curve equation y^2 = x^3 + ax + b
 // Constants for secp256k1 curve are a = 0 and b = 7
 // Thus secp256k1 curve becomes y^2 = x^3 + 7
-lazy_static::lazy_static! {
-    // b = 7 in secp256k1 curve y^2 = x^3 + 7
-    pub static ref SECP256K1_B: FieldElement = FieldElement::new(BigInt::from(7)).unwrap();
-
-    // just field_element = 2 for short usage
-    pub static ref TWO: FieldElement = FieldElement::new(BigInt::from(2)).unwrap();
-
-    // just field_element = 3 for short usage
-    pub static ref THREE: FieldElement = FieldElement::new(BigInt::from(3)).unwrap();
-
-    // Group Order N in secp256k1 curve: N * G = Point::Infinity
-    pub static ref SECP256K1_N: BigInt = BigInt::parse_bytes(b"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141", 16).unwrap();
-
-    // Generator point G in secp256k1 curve: N * G = Point::Infinity
-    pub static ref G: Point = {
-        let x = FieldElement::new(BigInt::parse_bytes(b"79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798", 16).unwrap()).unwrap();
-        let y = FieldElement::new(BigInt::parse_bytes(b"483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8", 16).unwrap()).unwrap();
-        Point::new(Some(x), Some(y)).unwrap()
-    };
-}
-
-/// Represents a point on the secp256k1 elliptic curve.
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub enum Point {
-    Infinity,
-    Coordinates { x: FieldElement, y: FieldElement },
-}
-
-impl Point {
-    /// Constructs a new `Point` on secp256k1: y^2 = x^3 + 7.
-    /// If both x and y are None, returns the point at infinity.
-    /// If both are Some, validates the curve equation.
-    pub fn new(x: Option<FieldElement>, y: Option<FieldElement>) -> Result<Self, String> {
-        match (x, y) {
-            // Point at infinity
-            (None, None) => Ok(Point::Infinity),
-
-            // Regular point with both coordinates
-            (Some(x), Some(y)) => {
-                // Check that the point lies on the curve: y^2 = x^3 + 7
-                let left = y.pow(BigInt::from(2));
-                let right = &x.pow(BigInt::from(3)) + &*SECP256K1_B;
-                if left == right {
-                    Ok(Point::Coordinates { x, y })
-                } else {
-                    Err(format!(
-                        "Point ({}, {}) is not on the secp256k1 curve",
-                        x, y
-                    ))
-                }
-            }
-            _ => {
-                Err("Invalid point: both coordinates must be Some or both must be None".to_string())
-            }
-        }
+// All constants are built at compile time, so they need neither std nor lazy initialization.
+
+// b = 7 in secp256k1 curve y^2 = x^3 + 7
+pub const SECP256K1_B: FieldElement = FieldElement::from_limbs([7, 0, 0, 0]);
+
+// just field_element = 2 for short usage
+pub const TWO: FieldElement = FieldElement::from_limbs([2, 0, 0, 0]);
+
+// just field_element = 3 for short usage
+pub const THREE: FieldElement = FieldElement::from_limbs([3, 0, 0, 0]);
+
+// Group Order N in secp256k1 curve: N * G = Point::Infinity
+// Stored as little-endian limbs; `Scalar::prime()` returns it as a `BigInt`.
+pub const SECP256K1_N: [u64; 4] =
+    modulus_limbs("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+
+// Generator point G in secp256k1 curve: N * G = Point::Infinity
+pub const G: Point = Point::Coordinates {
+    x: FieldElement::from_limbs(modulus_limbs(
+        "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    )),
+    y: FieldElement::from_limbs(modulus_limbs(
+        "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    )),
+};
+
+/// Marker type for secp256k1: y^2 = x^3 + 7 over F_p, with generator G of prime order N.
+/// Overrides the generic curve arithmetic with Jacobian additions and windowed (or GLV)
+/// multiplication, which assume a = 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Secp256k1;
+
+impl Curve for Secp256k1 {
+    const NAME: &'static str = "secp256k1";
+    const COFACTOR: u64 = 1;
+    type Field = Secp256k1Field;
+    type Order = Secp256k1Order;
+
+    fn a() -> FieldElement {
+        FieldElement::zero()
     }
 
-    /// Performs point doubling: P + P on the secp256k1 elliptic curve.
-    fn point_double(&self) -> Point {
-        if let Point::Coordinates { x, y } = self {
-            if y == &FieldElement::zero() {
-                return Point::Infinity;
-            }
-
-            // s = (3 * x^2) / (2 * y)
-            let numerator = &*THREE * &(x.pow(BigInt::from(2)));
-            let denominator = &*TWO * y;
-            let s = &numerator / &denominator;
-
-            // x3 = s^2 - 2*x
-            let x3 = &(s.pow(BigInt::from(2))) - &(x + x);
+    fn b() -> FieldElement {
+        SECP256K1_B.clone()
+    }
 
-            // y3 = s * (x - x3) - y
-            let x_minus_x3 = x - &x3;
-            let s_times_diff = &s * &x_minus_x3;
-            let y3 = &s_times_diff - y;
+    fn generator() -> Point {
+        G
+    }
 
-            Point::Coordinates { x: x3, y: y3 }
-        } else {
-            Point::Infinity
+    /// Performs the addition in Jacobian coordinates (covering P + Q, P + P and P + (-P)),
+    /// then converts the result back to affine with one inversion.
+    fn add(p: &Point, q: &Point) -> Point {
+        match (p, q) {
+            (Point::Infinity, _) => q.clone(),
+            (_, Point::Infinity) => p.clone(),
+            _ => JacobianPoint::from_affine(p).add_mixed(q).to_affine(),
         }
     }
 
-    /// Performs point addition for two distinct points P + Q where P ≠ Q on the secp256k1 elliptic curve.
-    fn point_add_distinct(&self, other: &Point) -> Point {
-        if let (Point::Coordinates { x: x1, y: y1 }, Point::Coordinates { x: x2, y: y2 }) =
-            (self, other)
-        {
-            // s = (y2 - y1) / (x2 - x1)
-            let s = &(y2 - y1) / &(x2 - x1);
-
-            // x3 = s^2 - x1 - x2
-            let s_squared = s.pow(BigInt::from(2));
-            let s_squared_minus_x1 = &s_squared - x1;
-            let x3 = &s_squared_minus_x1 - x2;
-
-            // y3 = s * (x1 - x3) - y1
-            let x1_minus_x3 = x1 - &x3;
-            let s_times_diff = &s * &x1_minus_x3;
-            let y3 = &s_times_diff - y1;
-
-            Point::Coordinates { x: x3, y: y3 }
-        } else {
-            Point::Infinity
-        }
+    /// Uses the fixed 4-bit window by default, or the GLV endomorphism with the `glv` feature.
+    fn mul(point: &Point, k: &Scalar) -> Point {
+        #[cfg(feature = "glv")]
+        let result = glv::mul(point, k);
+        #[cfg(not(feature = "glv"))]
+        let result = point.mul_windowed(k);
+        result.to_affine()
     }
 }
 
-/// Formats a `Point` as a string for display purposes.
-impl fmt::Display for Point {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Point::Infinity => write!(f, "Point(Infinity)"),
-            Point::Coordinates { x, y } => {
-                write!(f, "Point(x=0x{:064x}, y=0x{:064x})", x.num(), y.num())
-            }
-        }
+/// Represents a point on the secp256k1 elliptic curve.
+pub type Point = CurvePoint<Secp256k1>;
+
+impl Point {
+    /// Computes k * P in constant time with respect to the scalar k.
+    /// Uses a fixed 256-step Montgomery ladder on complete projective formulas, so it is the
+    /// method to use with secret scalars such as private keys and nonces.
+    pub fn mul_ct(&self, k: &Scalar) -> Point {
+        ProjectivePoint::from_affine(self).mul_ct(k).to_affine()
     }
-}
 
-/// Implement Add for references to Point
-impl<'a> Add<&'a Point> for &Point {
-    type Output = Point;
-    fn add(self, rhs: &'a Point) -> Point {
-        match (self, rhs) {
-            (Point::Infinity, _) => rhs.clone(),
-            (_, Point::Infinity) => self.clone(),
-            (Point::Coordinates { x: x1, y: y1 }, Point::Coordinates { x: x2, y: y2 }) => {
-                if x1 == x2 {
-                    if y1 == y2 {
-                        self.point_double() // P + P
-                    } else {
-                        Point::Infinity // P + (-P) = infinity
-                    }
-                } else {
-                    self.point_add_distinct(rhs) // P + Q
-                }
-            }
-        }
+    /// Computes k * G for the secp256k1 generator using a lazily built precomputed table.
+    /// Much faster than `&G * &k` or `G.mul_ct(&k)` and, like `mul_ct`, constant time in k,
+    /// so it is the method to use for key generation and signing nonces.
+    pub fn mul_generator(k: &Scalar) -> Point {
+        generator_table::mul_generator(k).to_affine()
     }
-}
 
-/// Implement Add for owned Point values
-impl Add for Point {
-    type Output = Point;
-    fn add(self, rhs: Point) -> Point {
-        &self + &rhs
+    /// Computes the sum k_1 * P_1 + ... + k_n * P_n in one pass.
+    /// Uses Strauss–Shamir interleaving (shared doublings) for small inputs such as the
+    /// u1 * G + u2 * Q of ECDSA verification, and Pippenger's bucket method for large batches.
+    /// Runs in variable time, so it is meant for public scalars (verification).
+    pub fn multi_mul(terms: &[(Scalar, Point)]) -> Point {
+        multi_mul::multi_mul(terms).to_affine()
     }
-}
 
-/// Implement Mul for references to Point and BigInt
-impl Mul<&BigInt> for &Point {
-    type Output = Point;
-    fn mul(self, rhs: &BigInt) -> Point {
-        let mut k = rhs % &*SECP256K1_N;
-        if k < BigInt::zero() {
-            k += &*SECP256K1_N; // Handle negative scalars
+    /// Computes k * P with a fixed 4-bit window.
+    /// Precomputes 1P..15P (normalized to affine in one batch), then processes the scalar nibble by
+    /// nibble with four Jacobian doublings and at most one mixed addition each, inverting only at the end.
+    #[cfg_attr(feature = "glv", allow(dead_code))]
+    pub(crate) fn mul_windowed(&self, k: &Scalar) -> JacobianPoint {
+        if *self == Point::Infinity || k.is_zero() {
+            return JacobianPoint::infinity();
         }
 
-        let mut result = Point::Infinity;
-        let mut current = self.clone();
+        let mut multiples = Vec::with_capacity(15);
+        let mut current = JacobianPoint::from_affine(self);
+        multiples.push(current.clone());
+        for _ in 1..15 {
+            current = current.add_mixed(self);
+            multiples.push(current.clone());
+        }
+        let table = JacobianPoint::batch_to_affine(&multiples);
 
-        while k > BigInt::zero() {
-            if &k & BigInt::one() == BigInt::one() {
-                result = &result + &current;
+        let mut result = JacobianPoint::infinity();
+        for byte in k.to_be_bytes() {
+            for nibble in [byte >> 4, byte & 0x0f] {
+                for _ in 0..4 {
+                    result = result.double();
+                }
+                if nibble != 0 {
+                    result = result.add_mixed(&table[nibble as usize - 1]);
+                }
             }
-            current = &current + &current;
-            k >>= 1;
         }
         result
     }
 }
-
-/// Implement Mul for owned Point and BigInt
-impl Mul<BigInt> for Point {
-    type Output = Point;
-
-    fn mul(self, rhs: BigInt) -> Point {
-        // Delegate to the reference version
-        &self * &rhs
-    }
-}
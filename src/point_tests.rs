@@ -1,15 +1,15 @@
+use crate::error::Error;
 use crate::finite_fields::FieldElement;
-use crate::point::{G, Point, SECP256K1_N};
+use crate::point::{G, Point};
+use crate::scalar::Scalar;
 use num_bigint::BigInt;
 use num_traits::{One, Zero};
 use std::string::ToString;
 
 // Helper function to create a Point from hexadecimal coordinates
 fn point_from_hex(x_hex: &str, y_hex: &str) -> Point {
-    let x_bigint = BigInt::parse_bytes(x_hex.as_bytes(), 16).unwrap();
-    let y_bigint = BigInt::parse_bytes(y_hex.as_bytes(), 16).unwrap();
-    let x = FieldElement::new(x_bigint).unwrap();
-    let y = FieldElement::new(y_bigint).unwrap();
+    let x = FieldElement::from_hex(x_hex).unwrap();
+    let y = FieldElement::from_hex(y_hex).unwrap();
     Point::new(Some(x), Some(y)).unwrap()
 }
 
@@ -22,7 +22,7 @@ fn test_valid_point() {
     // Test that a valid point (generator G) is created successfully
     let p = Point::new(Some(G.x().clone()), Some(G.y().clone()));
     assert!(p.is_ok(), "Generator point should satisfy y^2 = x^3 + 7");
-    assert_eq!(p.unwrap(), *G);
+    assert_eq!(p.unwrap(), G);
 }
 
 #[test]
@@ -32,8 +32,7 @@ fn test_invalid_point() {
     let y = FieldElement::new(BigInt::from(2)).unwrap();
     let p = Point::new(Some(x), Some(y));
     assert!(p.is_err(), "Point (1, 2) should be invalid");
-    let error_msg = p.unwrap_err();
-    assert!(error_msg.contains("is not on the secp256k1 curve"));
+    assert_eq!(p.unwrap_err(), Error::NotOnCurve);
 }
 
 #[test]
@@ -58,6 +57,8 @@ fn test_invalid_point_mixed_none() {
         p2.is_err(),
         "Point with only y coordinate should be invalid"
     );
+    assert_eq!(p1.unwrap_err(), Error::MismatchedCoordinates);
+    assert_eq!(p2.unwrap_err(), Error::MismatchedCoordinates);
 }
 
 //--------------
@@ -78,6 +79,43 @@ fn test_display_infinity_point() {
     assert_eq!(p.to_string(), "Point(Infinity)");
 }
 
+//-------------------------
+// String Conversion Tests
+//-------------------------
+
+#[test]
+fn test_from_str_round_trip() {
+    // Test that parsing the Display output gives back the same point, including infinity.
+    let p = &G * &BigInt::from(2024);
+    assert_eq!(p.to_string().parse::<Point>().unwrap(), p);
+    assert_eq!("Point(Infinity)".parse::<Point>().unwrap(), Point::Infinity);
+}
+
+#[test]
+fn test_from_str_rejects_invalid_input() {
+    // Test that malformed strings and off-curve coordinates are rejected with the right error.
+    assert_eq!("Point(1, 2)".parse::<Point>(), Err(Error::InvalidFormat));
+    assert_eq!(
+        "Point(x=0xzz, y=0x02)".parse::<Point>(),
+        Err(Error::InvalidHex)
+    );
+    assert_eq!(
+        "Point(x=0x01, y=0x02)".parse::<Point>(),
+        Err(Error::NotOnCurve)
+    );
+}
+
+#[test]
+fn test_point_hex_round_trip() {
+    // Test that to_hex and from_hex round-trip through both SEC encodings.
+    let p = &G * &BigInt::from(5001);
+    let compressed = "0357a4f368868a8a6d572991e484e664810ff14c05c0fa023275251151fe0e53d1";
+    assert_eq!(p.to_hex(true), compressed);
+    assert_eq!(Point::from_hex(compressed).unwrap(), p);
+    assert_eq!(Point::from_hex(&p.to_hex(false)).unwrap(), p);
+    assert_eq!(Point::from_hex("02zz"), Err(Error::InvalidHex));
+}
+
 //---------------
 // Addition Tests
 //---------------
@@ -93,7 +131,7 @@ fn test_point_addition_distinct_points() {
         "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
         "388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672",
     );
-    let sum = &*G + &two_g;
+    let sum = &G + &two_g;
     assert_ne!(
         sum,
         Point::Infinity,
@@ -109,7 +147,7 @@ fn test_point_doubling_generator() {
         "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
         "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
     );
-    let double_g = &*G + &*G;
+    let double_g = &G + &G;
     assert_eq!(double_g, two_g);
 }
 
@@ -132,7 +170,7 @@ fn test_point_addition_inverse() {
     // Test that adding a point to its inverse yields infinity: P + (-P) = ∞
     let neg_y = -G.y();
     let neg_p = Point::new(Some(G.x().clone()), Some(neg_y)).unwrap();
-    let sum = &*G + &neg_p;
+    let sum = &G + &neg_p;
     assert_eq!(sum, Point::Infinity);
 }
 
@@ -143,8 +181,8 @@ fn test_point_addition_commutative() {
         "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
         "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
     );
-    let sum1 = &*G + &two_g;
-    let sum2 = &two_g + &*G;
+    let sum1 = &G + &two_g;
+    let sum2 = &two_g + &G;
     assert_eq!(sum1, sum2);
 }
 
@@ -152,8 +190,8 @@ fn test_point_addition_commutative() {
 fn test_add_to_infinity() {
     // Test addition with infinity: P + ∞ = P, ∞ + P = P, ∞ + ∞ = ∞
     let infinity = Point::new(None, None).unwrap();
-    assert_eq!(&*G + &infinity, *G, "P + ∞ should equal P");
-    assert_eq!(&infinity + &*G, *G, "∞ + P should equal P");
+    assert_eq!(&G + &infinity, G, "P + ∞ should equal P");
+    assert_eq!(&infinity + &G, G, "∞ + P should equal P");
     assert_eq!(&infinity + &infinity, infinity, "∞ + ∞ should equal ∞");
 }
 
@@ -168,8 +206,8 @@ fn test_add_associativity() {
         "e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13",
         "51ed993ea0d455b75642e2098ea51448d967ae33bfbdfe40cfe97bdc47739922",
     );
-    let result1 = &(&*G + &*G) + &two_g;
-    let result2 = &*G + &(&*G + &two_g);
+    let result1 = &(&G + &G) + &two_g;
+    let result2 = &G + &(&G + &two_g);
     assert_eq!(result1, four_g);
     assert_eq!(result2, four_g);
 }
@@ -192,15 +230,15 @@ fn test_add_owned() {
 #[test]
 fn test_scalar_mul_zero() {
     // Test that 0 * G = ∞
-    let result = &*G * &BigInt::zero();
+    let result = &G * &BigInt::zero();
     assert_eq!(result, Point::Infinity);
 }
 
 #[test]
 fn test_scalar_mul_one() {
     // Test that 1 * G = G
-    let result = &*G * &BigInt::one();
-    assert_eq!(result, *G);
+    let result = &G * &BigInt::one();
+    assert_eq!(result, G);
 }
 
 #[test]
@@ -210,7 +248,7 @@ fn test_scalar_mul_two() {
         "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
         "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
     );
-    let result = &*G * &BigInt::from(2);
+    let result = &G * &BigInt::from(2);
     assert_eq!(result, two_g);
 }
 
@@ -221,14 +259,14 @@ fn test_scalar_mul_three() {
         "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
         "388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672",
     );
-    let result = &*G * &BigInt::from(3);
+    let result = &G * &BigInt::from(3);
     assert_eq!(result, three_g);
 }
 
 #[test]
 fn test_scalar_mul_n() {
     // Test that N * G = ∞ (group order)
-    let result = &*G * &*SECP256K1_N;
+    let result = &G * Scalar::prime();
     assert_eq!(result, Point::Infinity);
 }
 
@@ -239,17 +277,17 @@ fn test_scalar_mul_large_k() {
         "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
         "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
     );
-    let large_k = &*SECP256K1_N + BigInt::from(2);
-    let result = &*G * &large_k;
+    let large_k = Scalar::prime() + BigInt::from(2);
+    let result = &G * &large_k;
     assert_eq!(result, two_g);
 }
 
 #[test]
 fn test_scalar_mul_negative() {
     // Test that -1 * G = (N - 1) * G
-    let neg_one_result = &*G * &BigInt::from(-1);
-    let n_minus_one = &*SECP256K1_N - BigInt::one();
-    let n_minus_one_result = &*G * &n_minus_one;
+    let neg_one_result = &G * &BigInt::from(-1);
+    let n_minus_one = Scalar::prime() - BigInt::one();
+    let n_minus_one_result = &G * &n_minus_one;
     assert_eq!(neg_one_result, n_minus_one_result);
 }
 
@@ -274,6 +312,60 @@ fn test_scalar_mul_owned() {
     assert_eq!(result, two_g);
 }
 
+//------------------------------------
+// Fixed-Base Multiplication Tests
+//------------------------------------
+
+#[test]
+fn test_mul_generator_small_scalars() {
+    // Test that the table-based k * G agrees with repeated addition for k = 0..=40,
+    // covering every digit of the lowest windows.
+    let mut expected = Point::Infinity;
+    for k in 0..=40u64 {
+        let scalar = Scalar::new(BigInt::from(k)).unwrap();
+        assert_eq!(Point::mul_generator(&scalar), expected);
+        expected = &expected + &G;
+    }
+}
+
+#[test]
+fn test_mul_generator_large_scalars() {
+    // Test that the table-based k * G agrees with the generic multiplication on large scalars.
+    let n_minus_one = Scalar::new(Scalar::prime() - BigInt::one()).unwrap();
+    let mixed = Scalar::new(
+        BigInt::parse_bytes(
+            b"f00dbabedeadbeef0123456789abcdef00112233445566778899aabbccddeeff",
+            16,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    for k in [n_minus_one, mixed] {
+        assert_eq!(Point::mul_generator(&k), &G * &k);
+    }
+}
+
+#[test]
+fn test_mul_generator_known_vectors() {
+    // Test published k * G vectors for secp256k1 (k = 112233445566778899 and a random 256-bit k).
+    let vectors = [
+        (
+            "18ebbb95eed0e13",
+            "a90cc3d3f3e146daadfc74ca1372207cb4b725ae708cef713a98edd73d99ef29",
+            "5a79d6b289610c68bc3b47f3d72f9788a26a06868b4d8e433e1e2ad76fb7dc76",
+        ),
+        (
+            "aa5e28d6a97a2479a65527f7290311a3624d4cc0fa1578598ee3c2613bf99522",
+            "34f9460f0e4f08393d192b3c5133a6ba099aa0ad9fd54ebccfacdfa239ff49c6",
+            "0b71ea9bd730fd8923f6d25a7a91e7dd7728a960686cb5a901bb419e0f2ca232",
+        ),
+    ];
+    for (k_hex, x_hex, y_hex) in vectors {
+        let k = Scalar::new(BigInt::parse_bytes(k_hex.as_bytes(), 16).unwrap()).unwrap();
+        assert_eq!(Point::mul_generator(&k), point_from_hex(x_hex, y_hex));
+    }
+}
+
 // Helper methods for coordinate access (needed for tests)
 impl Point {
     pub fn x(&self) -> &FieldElement {
@@ -0,0 +1,142 @@
+use crate::finite_fields::FieldElement;
+use crate::point::Point;
+use crate::scalar::Scalar;
+use subtle::Choice;
+
+// 3 * b = 21 for secp256k1, the constant used by the complete formulas
+const B3: FieldElement = FieldElement::from_limbs([21, 0, 0, 0]);
+
+/// A secp256k1 point in homogeneous projective coordinates (X : Y : Z), representing (X/Z, Y/Z).
+/// The point at infinity is (0 : 1 : 0) and needs no special casing.
+/// Addition and doubling use the complete formulas of Renes, Costello and Batina (2016, Algorithms 7 and 9
+/// for a = 0), which are correct for every input pair and contain no data-dependent branches.
+#[derive(Clone, Debug)]
+pub(crate) struct ProjectivePoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+}
+
+impl ProjectivePoint {
+    /// Returns the point at infinity (0 : 1 : 0).
+    pub(crate) fn identity() -> Self {
+        ProjectivePoint {
+            x: FieldElement::zero(),
+            y: FieldElement::one(),
+            z: FieldElement::zero(),
+        }
+    }
+
+    /// Lifts an affine point to projective coordinates with Z = 1.
+    pub(crate) fn from_affine(point: &Point) -> Self {
+        match point {
+            Point::Infinity => Self::identity(),
+            Point::Coordinates { x, y } => ProjectivePoint {
+                x: x.clone(),
+                y: y.clone(),
+                z: FieldElement::one(),
+            },
+        }
+    }
+
+    /// Converts back to affine coordinates with a single inversion.
+    pub(crate) fn to_affine(&self) -> Point {
+        if self.z.is_zero() {
+            return Point::Infinity;
+        }
+        let z_inv = self.z.inverse();
+        Point::Coordinates {
+            x: &self.x * &z_inv,
+            y: &self.y * &z_inv,
+        }
+    }
+
+    /// Complete point addition (RCB Algorithm 7, a = 0): valid for P = Q, P = -Q and infinity.
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1 * x2;
+        let t1 = y1 * y2;
+        let t2 = z1 * z2;
+        let t3 = &(x1 + y1) * &(x2 + y2);
+        let t3 = &t3 - &(&t0 + &t1);
+        let t4 = &(y1 + z1) * &(y2 + z2);
+        let t4 = &t4 - &(&t1 + &t2);
+        let y3 = &(x1 + z1) * &(x2 + z2);
+        let y3 = &y3 - &(&t0 + &t2);
+        let t0 = &(&t0 + &t0) + &t0;
+        let t2 = &B3 * &t2;
+        let z3 = &t1 + &t2;
+        let t1 = &t1 - &t2;
+        let y3 = &B3 * &y3;
+        let x3 = &(&t3 * &t1) - &(&t4 * &y3);
+        let y3 = &(&t1 * &z3) + &(&y3 * &t0);
+        let z3 = &(&z3 * &t4) + &(&t0 * &t3);
+
+        ProjectivePoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Complete point doubling (RCB Algorithm 9, a = 0): valid for every input including infinity.
+    pub(crate) fn double(&self) -> Self {
+        let (x, y, z) = (&self.x, &self.y, &self.z);
+
+        let t0 = y.square();
+        let z3 = &t0 + &t0;
+        let z3 = &z3 + &z3;
+        let z3 = &z3 + &z3;
+        let t1 = y * z;
+        let t2 = &B3 * &z.square();
+        let x3 = &t2 * &z3;
+        let y3 = &t0 + &t2;
+        let z3 = &t1 * &z3;
+        let t2 = &(&t2 + &t2) + &t2;
+        let t0 = &t0 - &t2;
+        let y3 = &x3 + &(&t0 * &y3);
+        let t1 = x * y;
+        let x3 = &t0 * &t1;
+        let x3 = &x3 + &x3;
+
+        ProjectivePoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Returns `b` if `choice` is set and `a` otherwise, without branching on `choice`.
+    pub(crate) fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        ProjectivePoint {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            z: FieldElement::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+
+    /// Swaps `a` and `b` if `choice` is set, without branching on `choice`.
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        FieldElement::conditional_swap(&mut a.x, &mut b.x, choice);
+        FieldElement::conditional_swap(&mut a.y, &mut b.y, choice);
+        FieldElement::conditional_swap(&mut a.z, &mut b.z, choice);
+    }
+
+    /// Computes k * P with a Montgomery ladder over all 256 bits of k.
+    /// Every iteration performs one addition and one doubling and selects operands with
+    /// conditional swaps, so the sequence of operations does not depend on the bits of k.
+    pub(crate) fn mul_ct(&self, k: &Scalar) -> Self {
+        let mut r0 = Self::identity();
+        let mut r1 = self.clone();
+        for i in (0..256).rev() {
+            let bit = Choice::from(((k.limbs()[i / 64] >> (i % 64)) & 1) as u8);
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+            r1 = r0.add(&r1);
+            r0 = r0.double();
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
+}
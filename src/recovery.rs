@@ -0,0 +1,110 @@
+// Public key recovery for ECDSA (SEC1 section 4.1.6).
+// A signature (r, s) on z fixes the nonce point R up to two choices of y and, in rare cases,
+// two choices of x (r itself, or r + N when R.x was at least N). The recovery id picks one:
+// bit 0 is the parity of R.y and bit 1 selects x = r + N. From R the key follows as
+// Q = r^-1 (s * R - z * G), so a 65-byte signature is enough to identify the signer.
+//
+// The compact encoding is Bitcoin Core's: a header byte 27 + recovery id, plus 4 if the
+// signer's key is serialized compressed, followed by r and s as 32 big-endian bytes each.
+
+use crate::ecdsa::Signature;
+use crate::error::Error;
+use crate::keys::{PrivateKey, PublicKey};
+use crate::limbs;
+use crate::point::{G, Point, SECP256K1_N};
+use crate::scalar::Scalar;
+
+/// An ECDSA signature together with the recovery id (0 to 3) that locates its nonce point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecoverableSignature {
+    signature: Signature,
+    recovery_id: u8,
+}
+
+impl RecoverableSignature {
+    /// Constructs a recoverable signature.
+    /// Returns `Error::OutOfRange` if the recovery id is not between 0 and 3.
+    pub fn new(signature: Signature, recovery_id: u8) -> Result<Self, Error> {
+        if recovery_id > 3 {
+            return Err(Error::OutOfRange);
+        }
+        Ok(RecoverableSignature {
+            signature,
+            recovery_id,
+        })
+    }
+
+    /// Returns the plain signature (r, s).
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// Returns the recovery id: bit 0 is the parity of R.y, bit 1 is set if R.x = r + N.
+    pub fn recovery_id(&self) -> u8 {
+        self.recovery_id
+    }
+
+    /// Encodes the signature as 65 bytes: the header 27 + recovery id (+ 4 if `compressed`),
+    /// then r and s.
+    pub fn to_compact(&self, compressed: bool) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[0] = 27 + self.recovery_id + if compressed { 4 } else { 0 };
+        bytes[1..33].copy_from_slice(&self.signature.r().to_be_bytes());
+        bytes[33..].copy_from_slice(&self.signature.s().to_be_bytes());
+        bytes
+    }
+
+    /// Parses the 65-byte compact encoding and returns the signature with the compressed flag.
+    /// Returns `Error::InvalidPrefix` for headers outside 27..=34 and `Error::OutOfRange` if
+    /// r or s is zero or not below N.
+    pub fn from_compact(bytes: &[u8; 65]) -> Result<(Self, bool), Error> {
+        let header = bytes[0];
+        if !(27..=34).contains(&header) {
+            return Err(Error::InvalidPrefix(header));
+        }
+        let r = Scalar::from_be_bytes(bytes[1..33].try_into().unwrap())?;
+        let s = Scalar::from_be_bytes(bytes[33..].try_into().unwrap())?;
+        let recovery_id = (header - 27) & 3;
+        let signature = RecoverableSignature::new(Signature::new(r, s)?, recovery_id)?;
+        Ok((signature, header >= 31))
+    }
+
+    /// Recovers the public key that produced this signature on the message hash `z`.
+    /// Returns `Error::OutOfRange` if r + N is not a valid x-coordinate, `Error::NotOnCurve` if
+    /// no curve point has the x-coordinate, and `Error::PointAtInfinity` if the result is infinity.
+    pub fn recover(&self, z: &Scalar) -> Result<PublicKey, Error> {
+        let r = self.signature.r();
+        let x = if self.recovery_id & 2 == 0 {
+            *r.limbs()
+        } else {
+            let (sum, carry) = limbs::add(r.limbs(), &SECP256K1_N);
+            if carry != 0 {
+                return Err(Error::OutOfRange);
+            }
+            sum
+        };
+        // Decompress R from its x-coordinate and y parity, as a compressed SEC1 point
+        let mut sec = [0u8; 33];
+        sec[0] = 0x02 | (self.recovery_id & 1);
+        sec[1..].copy_from_slice(&limbs::to_be_bytes(&x));
+        let nonce_point = Point::from_sec(&sec)?;
+
+        // Q = r^-1 (s * R - z * G) = (-z / r) * G + (s / r) * R
+        let r_inv = r.inverse();
+        let u1 = -&(z * &r_inv);
+        let u2 = self.signature.s() * &r_inv;
+        PublicKey::from_point(Point::multi_mul(&[(u1, G), (u2, nonce_point)]))
+    }
+}
+
+impl PrivateKey {
+    /// Signs the message hash `z` with a deterministic RFC 6979 nonce and returns the signature
+    /// with its recovery id. The plain signature is the same as the one from `sign`.
+    pub fn sign_recoverable(&self, z: &Scalar) -> RecoverableSignature {
+        let (signature, recovery_id) = self.sign_deterministic(z, None);
+        RecoverableSignature {
+            signature,
+            recovery_id,
+        }
+    }
+}
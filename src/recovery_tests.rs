@@ -0,0 +1,146 @@
+use crate::ecdsa::Signature;
+use crate::error::Error;
+use crate::keys::PrivateKey;
+use crate::recovery::RecoverableSignature;
+use crate::scalar::Scalar;
+use crate::test_util::{private_key, scalar};
+use proptest::prelude::*;
+use sha2::{Digest, Sha256};
+
+//------------------------------
+// Signing and Encoding Tests
+//------------------------------
+
+#[test]
+fn test_sign_recoverable_vector() {
+    // Test the compact signature of "Satoshi Nakamoto" under key 1: the RFC 6979 signature
+    // with recovery id 1, since normalizing s flipped the parity of the nonce point.
+    let key = private_key(1);
+    let z = Scalar::reduce_be_bytes(&Sha256::digest(b"Satoshi Nakamoto").into());
+    let signature = key.sign_recoverable(&z);
+    assert_eq!(signature.signature(), &key.sign(&z));
+    assert_eq!(signature.recovery_id(), 1);
+    assert_eq!(
+        crate::hex::encode(&signature.to_compact(true)),
+        "20934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d82442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"
+    );
+    assert_eq!(signature.recover(&z).unwrap(), *key.public_key());
+}
+
+#[test]
+fn test_compact_round_trip() {
+    // Test that the header records the recovery id and the compressed flag.
+    let signature = private_key(5000).sign_recoverable(&Scalar::one());
+    for compressed in [true, false] {
+        let bytes = signature.to_compact(compressed);
+        let expected_header = 27 + signature.recovery_id() + if compressed { 4 } else { 0 };
+        assert_eq!(bytes[0], expected_header);
+        let (parsed, parsed_compressed) = RecoverableSignature::from_compact(&bytes).unwrap();
+        assert_eq!(parsed, signature);
+        assert_eq!(parsed_compressed, compressed);
+    }
+}
+
+#[test]
+fn test_compact_invalid() {
+    // Test that unknown headers, zero values and r >= N are rejected.
+    let mut bytes = private_key(5000)
+        .sign_recoverable(&Scalar::one())
+        .to_compact(true);
+    for header in [0, 26, 35, 0xff] {
+        bytes[0] = header;
+        assert_eq!(
+            RecoverableSignature::from_compact(&bytes),
+            Err(Error::InvalidPrefix(header))
+        );
+    }
+    bytes[0] = 31;
+    let mut zero_r = bytes;
+    zero_r[1..33].fill(0);
+    assert_eq!(
+        RecoverableSignature::from_compact(&zero_r),
+        Err(Error::OutOfRange)
+    );
+    let mut large_s = bytes;
+    large_s[33..].fill(0xff);
+    assert_eq!(
+        RecoverableSignature::from_compact(&large_s),
+        Err(Error::OutOfRange)
+    );
+    let signature = RecoverableSignature::from_compact(&bytes).unwrap().0;
+    assert_eq!(
+        RecoverableSignature::new(signature.signature().clone(), 4),
+        Err(Error::OutOfRange)
+    );
+}
+
+//------------------------------
+// Recovery Tests
+//------------------------------
+
+#[test]
+fn test_recover_every_recovery_id() {
+    // Test recovery for all four ids with r = 2, where both x = 2 and x = 2 + N lie on the curve:
+    // each id gives a different key, and the signature verifies under each of them.
+    let signature = Signature::new(scalar("2"), scalar("1234567890abcdef")).unwrap();
+    let z = scalar("c90c269c4f8fcbe6880f72a721ddfbf1914268a794cbb21cfafee13770ae19f1");
+    let mut keys = Vec::new();
+    for recovery_id in 0..4 {
+        let recoverable = RecoverableSignature::new(signature.clone(), recovery_id).unwrap();
+        let public_key = recoverable.recover(&z).unwrap();
+        assert!(
+            public_key.verify(&z, &signature),
+            "recovery id {}",
+            recovery_id
+        );
+        assert!(!keys.contains(&public_key));
+        keys.push(public_key);
+    }
+}
+
+#[test]
+fn test_recover_overflow_out_of_range() {
+    // Test that x = r + N is rejected when it reaches 2^256 or the field prime p.
+    let s = scalar("1");
+    let z = scalar("1");
+    for r in [
+        "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+        "000000000000000000000000000000014551231950b75fc4402da1722fc9baee",
+    ] {
+        let signature = Signature::new(scalar(r), s.clone()).unwrap();
+        let recoverable = RecoverableSignature::new(signature, 2).unwrap();
+        assert_eq!(recoverable.recover(&z), Err(Error::OutOfRange), "r = {}", r);
+    }
+}
+
+#[test]
+fn test_recover_not_on_curve() {
+    // Test that an r that is not the x-coordinate of a curve point cannot be recovered.
+    // x = 5 is not on secp256k1: 5^3 + 7 = 132 is a non-residue mod p.
+    let signature = Signature::new(scalar("5"), scalar("1")).unwrap();
+    let recoverable = RecoverableSignature::new(signature, 0).unwrap();
+    assert_eq!(recoverable.recover(&scalar("1")), Err(Error::NotOnCurve));
+}
+
+#[test]
+fn test_recover_wrong_message() {
+    // Test that recovering with a different message hash yields a different key.
+    let key = private_key(0xdeadbeef);
+    let signature = key.sign_recoverable(&scalar("1"));
+    assert_ne!(signature.recover(&scalar("2")).unwrap(), *key.public_key());
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn prop_recover_signer(e in any::<[u8; 32]>(), z in any::<[u8; 32]>()) {
+        // Test that recovery returns the signer's key for arbitrary keys and messages.
+        let secret = Scalar::reduce_be_bytes(&e);
+        prop_assume!(!secret.is_zero());
+        let key = PrivateKey::new(secret).unwrap();
+        let z = Scalar::reduce_be_bytes(&z);
+        let signature = key.sign_recoverable(&z);
+        prop_assert_eq!(signature.recover(&z).unwrap(), key.public_key().clone());
+    }
+}
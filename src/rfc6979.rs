@@ -0,0 +1,70 @@
+// Deterministic ECDSA nonces (RFC 6979, section 3.2) with HMAC-SHA256.
+// The nonce is derived from the private key and the message hash, so signing needs no random
+// number generator and the same key never uses one nonce for two different messages.
+// Optional extra entropy is appended to the key material, as libsecp256k1 and Bitcoin Core do;
+// with it the signature stays valid but no longer matches the plain RFC 6979 one.
+
+use crate::scalar::Scalar;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Produces the sequence of candidate nonces of RFC 6979 for one key and message hash.
+/// The first candidate is the nonce; later ones are only needed if a candidate yields r = 0 or s = 0.
+pub(crate) struct NonceGenerator {
+    k: [u8; 32],
+    v: [u8; 32],
+}
+
+impl NonceGenerator {
+    /// Seeds the HMAC-DRBG with int2octets(secret) || bits2octets(z) || extra_entropy.
+    /// z is already a scalar, so bits2octets is just its 32-byte encoding.
+    pub(crate) fn new(secret: &Scalar, z: &Scalar, extra_entropy: Option<&[u8; 32]>) -> Self {
+        let mut generator = NonceGenerator {
+            k: [0x00; 32],
+            v: [0x01; 32],
+        };
+        let mut secret = secret.to_be_bytes();
+        let z = z.to_be_bytes();
+        let extra: &[u8] = extra_entropy.map_or(&[], |bytes| bytes);
+        for separator in [0x00, 0x01] {
+            generator.k = generator.hmac(&[&generator.v, &[separator], &secret, &z, extra]);
+            generator.v = generator.hmac(&[&generator.v]);
+        }
+        secret.zeroize();
+        generator
+    }
+
+    /// Returns the next candidate nonce in [1, N - 1], skipping values outside that range.
+    pub(crate) fn next_nonce(&mut self) -> Scalar {
+        loop {
+            self.v = self.hmac(&[&self.v]);
+            let candidate = Scalar::from_be_bytes(&self.v).ok().filter(|k| !k.is_zero());
+            // Step h.3: update K and V, so that the next call starts from a fresh state
+            self.k = self.hmac(&[&self.v, &[0x00]]);
+            self.v = self.hmac(&[&self.v]);
+            if let Some(k) = candidate {
+                return k;
+            }
+        }
+    }
+
+    /// Computes HMAC-SHA256 keyed with K over the concatenation of `parts`.
+    fn hmac(&self, parts: &[&[u8]]) -> [u8; 32] {
+        let mut mac = HmacSha256::new_from_slice(&self.k).expect("HMAC accepts any key length");
+        for part in parts {
+            mac.update(part);
+        }
+        mac.finalize().into_bytes().into()
+    }
+}
+
+/// Wipes K and V, from which every nonce (and so the private key) could be recomputed.
+impl Drop for NonceGenerator {
+    fn drop(&mut self) {
+        self.k.zeroize();
+        self.v.zeroize();
+    }
+}
@@ -0,0 +1,110 @@
+use crate::ecdsa::Signature;
+use crate::keys::PrivateKey;
+use crate::rfc6979::NonceGenerator;
+use crate::scalar::Scalar;
+use crate::test_util::scalar;
+use sha2::{Digest, Sha256};
+
+// Helper function hashing a message with single SHA-256 into a scalar
+fn sha256(message: &str) -> Scalar {
+    Scalar::reduce_be_bytes(&Sha256::digest(message.as_bytes()).into())
+}
+
+// The published secp256k1 vectors: (private key, message, nonce, r, s), with s in low form
+const VECTORS: [(&str, &str, &str, &str, &str); 4] = [
+    (
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "Satoshi Nakamoto",
+        "8f8a276c19f4149656b280621e358cce24f5f52542772691ee69063b74f15d15",
+        "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+        "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "All those moments will be lost in time, like tears in rain. Time to die...",
+        "38aa22d72376b4dbc472e06c3ba403ee0a394da63fc58d88686c611aba98d6b3",
+        "8600dbd41e348fe5c9465ab92d23e3db8b98b873beecd930736488696438cb6b",
+        "547fe64427496db33bf66019dacbf0039c04199abb0122918601db38a72cfc21",
+    ),
+    (
+        "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+        "Satoshi Nakamoto",
+        "33a19b60e25fb6f4435af53a3d42d493644827367e6453928554f43e49aa6f90",
+        "fd567d121db66e382991534ada77a6bd3106f0a1098c231e47993447cd6af2d0",
+        "6b39cd0eb1bc8603e159ef5c20a5c8ad685a45b06ce9bebed3f153d10d93bed5",
+    ),
+    (
+        "f8b8af8ce3c7cca5e300d33939540c10d45ce001b8f252bfbc57ba0342904181",
+        "Alan Turing",
+        "525a82b70e67874398067543fd84c83d30c175fdc45fdeee082fe13b1d7cfdf1",
+        "7063ae83e7f62bbb171798131b4a0564b956930092b33b07b395615d9ec7e15c",
+        "58dfcc1e00a35e1572f366ffe34ba0fc47db1e7189759b9fb233c5b05ab388ea",
+    ),
+];
+
+//------------------------
+// Nonce Vector Tests
+//------------------------
+
+#[test]
+fn test_nonce_vectors() {
+    // Test that the first nonce matches the published RFC 6979 secp256k1 vectors.
+    for (secret, message, k, _, _) in VECTORS {
+        let mut nonces = NonceGenerator::new(&scalar(secret), &sha256(message), None);
+        assert_eq!(nonces.next_nonce(), scalar(k), "message {:?}", message);
+    }
+}
+
+#[test]
+fn test_signature_vectors() {
+    // Test that `sign` uses the RFC 6979 nonce and produces the expected (r, s).
+    for (secret, message, _, r, s) in VECTORS {
+        let key = PrivateKey::new(scalar(secret)).unwrap();
+        let z = sha256(message);
+        let signature = key.sign(&z);
+        assert_eq!(signature, Signature::new(scalar(r), scalar(s)).unwrap());
+        assert!(key.public_key().verify(&z, &signature));
+    }
+}
+
+#[test]
+fn test_later_nonces_differ() {
+    // Test that retrying yields new candidates rather than repeating the first one.
+    let (secret, message, k, _, _) = VECTORS[0];
+    let mut nonces = NonceGenerator::new(&scalar(secret), &sha256(message), None);
+    assert_eq!(nonces.next_nonce(), scalar(k));
+    let second = nonces.next_nonce();
+    let third = nonces.next_nonce();
+    assert_ne!(second, scalar(k));
+    assert_ne!(second, third);
+}
+
+//------------------------
+// Extra Entropy Tests
+//------------------------
+
+#[test]
+fn test_extra_entropy_vector() {
+    // Test the nonce for extra data 1 (as a 32-byte big-endian counter), which libsecp256k1
+    // appends to the key material after the private key and the message hash.
+    let mut extra = [0u8; 32];
+    extra[31] = 1;
+    let mut nonces = NonceGenerator::new(&Scalar::one(), &sha256("Satoshi Nakamoto"), Some(&extra));
+    assert_eq!(
+        nonces.next_nonce(),
+        scalar("dba9a88a555f1d818bb6800d214a08c034e823bd41fdc86e6d85947faa189c55")
+    );
+}
+
+#[test]
+fn test_sign_with_entropy() {
+    // Test that extra entropy changes the signature, which still verifies and is deterministic.
+    let key = PrivateKey::new(Scalar::one()).unwrap();
+    let z = sha256("Satoshi Nakamoto");
+    let mut extra = [0u8; 32];
+    extra[0] = 0x2a;
+    let signature = key.sign_with_entropy(&z, &extra);
+    assert_ne!(signature, key.sign(&z));
+    assert_eq!(signature, key.sign_with_entropy(&z, &extra));
+    assert!(key.public_key().verify(&z, &signature));
+}
@@ -0,0 +1,30 @@
+use crate::finite_fields::{BigInt, Fp, ModulusCell, PrimeField, modulus_limbs};
+use crate::limbs::{self, Limbs, WideLimbs};
+use crate::point::SECP256K1_N;
+
+// c = 2^256 - N for the secp256k1 group order (about 2^129).
+const SECP256K1_N_C: Limbs = modulus_limbs("14551231950b75fc4402da1732fc9bebf");
+
+/// Marker type for the secp256k1 scalar field: integers modulo the group order N.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Secp256k1Order;
+
+impl PrimeField for Secp256k1Order {
+    const NAME: &'static str = "Scalar";
+
+    const MODULUS: [u64; 4] = SECP256K1_N;
+
+    fn modulus() -> &'static BigInt {
+        static MODULUS: ModulusCell = ModulusCell::new();
+        MODULUS.get(&Self::MODULUS)
+    }
+
+    /// N is also close to 2^256, so the special-form folding reduction applies (in four folds).
+    fn reduce_wide(wide: &WideLimbs) -> Limbs {
+        limbs::reduce_wide_special(wide, &SECP256K1_N_C, &Self::MODULUS)
+    }
+}
+
+/// A scalar modulo the secp256k1 group order N, used for private keys, nonces and signature values.
+/// It is a distinct type from `FieldElement`, so values mod N and values mod p cannot be mixed.
+pub type Scalar = Fp<Secp256k1Order>;
@@ -0,0 +1,145 @@
+use crate::limbs;
+use crate::point::{G, Point, SECP256K1_N};
+use crate::scalar::Scalar;
+use num_bigint::BigInt;
+use num_traits::One;
+
+// Helper function to create a Scalar from a small integer
+fn scalar(n: u64) -> Scalar {
+    Scalar::new(BigInt::from(n)).unwrap()
+}
+
+//------------------
+// Constructor Tests
+//------------------
+
+#[test]
+fn test_new_range_checked() {
+    // Test that N - 1 is accepted while N and negative values are rejected.
+    let n_minus_one = Scalar::prime() - BigInt::one();
+    assert!(Scalar::new(n_minus_one).is_ok());
+    assert!(Scalar::new(Scalar::prime().clone()).is_err());
+    assert!(Scalar::new(BigInt::from(-1)).is_err());
+}
+
+#[test]
+fn test_reduce() {
+    // Test that reduction maps N + 5 to 5 and -1 to N - 1.
+    assert_eq!(
+        Scalar::reduce(&(Scalar::prime() + BigInt::from(5))),
+        scalar(5)
+    );
+    let expected = Scalar::new(Scalar::prime() - BigInt::one()).unwrap();
+    assert_eq!(Scalar::reduce(&BigInt::from(-1)), expected);
+}
+
+#[test]
+fn test_prime_is_group_order() {
+    // Test that the scalar modulus is the group order N, not the field prime p.
+    assert_eq!(*Scalar::prime(), limbs::to_bigint(&SECP256K1_N));
+}
+
+//-------------
+// Display Test
+//-------------
+
+#[test]
+fn test_display() {
+    // Test that scalars are displayed with their own prefix and the group order.
+    let expected = "Scalar_0x00000000000000000000000000000000000000000000000000000000000000ff_(mod 0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141)";
+    assert_eq!(format!("{}", scalar(255)), expected);
+}
+
+#[test]
+fn test_from_str_round_trip() {
+    // Test that a scalar parses back from its Display form, but not from a field element's.
+    let expected = "Scalar_0x00000000000000000000000000000000000000000000000000000000000000ff_(mod 0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141)";
+    assert_eq!(expected.parse::<Scalar>().unwrap(), scalar(255));
+    let field_element = crate::finite_fields::FieldElement::one().to_string();
+    assert!(field_element.parse::<Scalar>().is_err());
+}
+
+//-----------------
+// Arithmetic Tests
+//-----------------
+
+#[test]
+fn test_add_wraps_at_n() {
+    // Test that (N - 1) + 2 = 1 mod N.
+    let n_minus_one = Scalar::new(Scalar::prime() - BigInt::one()).unwrap();
+    assert_eq!(&n_minus_one + &scalar(2), Scalar::one());
+}
+
+#[test]
+fn test_sub_wraps_at_n() {
+    // Test that 1 - 2 = N - 1 mod N.
+    let expected = Scalar::new(Scalar::prime() - BigInt::one()).unwrap();
+    assert_eq!(&scalar(1) - &scalar(2), expected);
+}
+
+#[test]
+fn test_mul_and_inverse() {
+    // Test that k * k^(-1) = 1 and that division agrees with multiplying by the inverse.
+    let k = scalar(123456789);
+    assert_eq!(&k * &k.inverse(), Scalar::one());
+    assert_eq!(&scalar(10) / &scalar(5), scalar(2));
+}
+
+#[test]
+fn test_negation() {
+    // Test that k + (-k) = 0 and -0 = 0.
+    let k = scalar(42);
+    assert_eq!(&k + &(-&k), Scalar::zero());
+    assert_eq!(-Scalar::zero(), Scalar::zero());
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn test_inverse_of_zero() {
+    // Test that inverting zero panics.
+    let _ = Scalar::zero().inverse();
+}
+
+//------------------------
+// Byte Conversion Tests
+//------------------------
+
+#[test]
+fn test_be_bytes_round_trip() {
+    // Test that serializing to 32 bytes and parsing back yields the same scalar.
+    let k = Scalar::new(Scalar::prime() - BigInt::from(7)).unwrap();
+    let bytes = k.to_be_bytes();
+    assert_eq!(Scalar::from_be_bytes(&bytes).unwrap(), k);
+
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    assert_eq!(Scalar::one().to_be_bytes(), one);
+    assert_eq!(Scalar::zero().to_be_bytes(), [0u8; 32]);
+}
+
+#[test]
+fn test_from_be_bytes_out_of_range() {
+    // Test that byte strings encoding values >= N are rejected, but can be reduced explicitly.
+    let bytes = [0xffu8; 32];
+    assert!(Scalar::from_be_bytes(&bytes).is_err());
+    let reduced = Scalar::reduce_be_bytes(&bytes);
+    let expected = (BigInt::one() << 256) - BigInt::one() - Scalar::prime();
+    assert_eq!(*reduced.num(), expected);
+}
+
+//----------------------------
+// Scalar Multiplication Tests
+//----------------------------
+
+#[test]
+fn test_point_mul_scalar_matches_bigint() {
+    // Test that multiplying by a Scalar agrees with multiplying by the equivalent BigInt.
+    let k = scalar(1000);
+    assert_eq!(&G * &k, &G * &BigInt::from(1000));
+}
+
+#[test]
+fn test_point_mul_scalar_zero() {
+    // Test that 0 * G = ∞.
+    assert_eq!(&G * &Scalar::zero(), Point::Infinity);
+}
@@ -0,0 +1,219 @@
+// BIP340 Schnorr signatures over secp256k1, as used by taproot.
+// Public keys are x-only: the 32-byte x-coordinate of a point, which stands for the point with
+// that x and an even y (`lift_x`). A private key e whose point e * G has odd y therefore signs
+// with d = N - e, whose point has the same x and even y.
+// Signing with nonce k, where R = k * G is again taken with even y: the signature is (R.x, s)
+// with s = k + c * d mod N and challenge c = hash_challenge(R.x || P.x || m). Verification
+// recomputes R = s * G - c * P and accepts if it is finite, has even y and R.x = r.
+// Every hash is tagged (BIP0340/aux, BIP0340/nonce, BIP0340/challenge), so none can be
+// replayed as another; the nonce mixes the key with 32 bytes of auxiliary randomness.
+//
+// A taproot output (BIP341) without a script tree pays to the internal key P tweaked as
+// Q = P + t * G with t = hash_TapTweak(P.x); its key-path spends are signed with d + t.
+
+use crate::curve::curve_rhs;
+use crate::error::Error;
+use crate::finite_fields::FieldElement;
+use crate::hash::tagged_hash;
+use crate::keys::{PrivateKey, PublicKey};
+use crate::point::{G, Point, Secp256k1};
+use crate::scalar::Scalar;
+use zeroize::Zeroize;
+
+/// An x-only public key (BIP340): the point with the given x-coordinate and an even y.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XOnlyPublicKey {
+    point: Point,
+}
+
+impl XOnlyPublicKey {
+    /// Returns the key whose point has x-coordinate `x` and even y.
+    /// Returns `Error::NotOnCurve` if x^3 + 7 is not a square, so no point has that x.
+    pub fn lift_x(x: &FieldElement) -> Result<Self, Error> {
+        let y = curve_rhs::<Secp256k1>(x).sqrt().ok_or(Error::NotOnCurve)?;
+        let y = if y.is_even() { y } else { -y };
+        Ok(XOnlyPublicKey {
+            point: Point::Coordinates { x: x.clone(), y },
+        })
+    }
+
+    /// Parses a 32-byte x-only key.
+    /// Returns `Error::OutOfRange` if x is not below p and `Error::NotOnCurve` if it is not
+    /// the x-coordinate of a curve point.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
+        Self::lift_x(&FieldElement::from_be_bytes(bytes)?)
+    }
+
+    /// Returns the 32-byte x-coordinate.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        x_bytes(&self.point)
+    }
+
+    /// Returns the curve point of the key, which has even y.
+    pub fn point(&self) -> &Point {
+        &self.point
+    }
+
+    /// Returns the taproot output key Q = P + t * G, with t = hash_TapTweak(P.x), of an
+    /// output that commits to this internal key and no script tree (BIP341).
+    /// Returns `Error::OutOfRange` if t is not below N and `Error::PointAtInfinity` if Q is
+    /// infinity; neither happens for any known key.
+    pub fn tap_tweak(&self) -> Result<Self, Error> {
+        let t = tap_tweak_hash(&self.to_bytes())?;
+        let output_key = PublicKey::from_point(&self.point + &Point::mul_generator(&t))?;
+        Ok(output_key.x_only())
+    }
+
+    /// Checks that `signature` signs `message` under this key.
+    pub fn verify_schnorr(&self, message: &[u8], signature: &SchnorrSignature) -> bool {
+        let r = signature.r.to_be_bytes();
+        let c = challenge(&r, &self.to_bytes(), message);
+        // R = s * G - c * P in one multi-scalar multiplication
+        let terms = [(signature.s.clone(), G), (-&c, self.point.clone())];
+        match Point::multi_mul(&terms) {
+            Point::Infinity => false,
+            Point::Coordinates { x, y } => y.is_even() && x == signature.r,
+        }
+    }
+}
+
+impl PublicKey {
+    /// Returns the x-only form of the key, dropping the parity of y.
+    pub fn x_only(&self) -> XOnlyPublicKey {
+        let point = match self.point() {
+            Point::Coordinates { x, y } if y.is_odd() => Point::Coordinates {
+                x: x.clone(),
+                y: -y,
+            },
+            point => point.clone(),
+        };
+        XOnlyPublicKey { point }
+    }
+}
+
+/// A BIP340 signature: the x-coordinate r of the nonce point and the scalar s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchnorrSignature {
+    r: FieldElement,
+    s: Scalar,
+}
+
+impl SchnorrSignature {
+    /// Parses the 64-byte encoding r || s.
+    /// Returns `Error::OutOfRange` if r is not below p or s is not below N.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<Self, Error> {
+        Ok(SchnorrSignature {
+            r: FieldElement::from_be_bytes(bytes[..32].try_into().unwrap())?,
+            s: Scalar::from_be_bytes(bytes[32..].try_into().unwrap())?,
+        })
+    }
+
+    /// Encodes the signature as 64 bytes: r then s, both big-endian.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r.to_be_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_be_bytes());
+        bytes
+    }
+
+    /// Returns r, the x-coordinate of the nonce point.
+    pub fn r(&self) -> &FieldElement {
+        &self.r
+    }
+
+    /// Returns s = k + c * d mod N.
+    pub fn s(&self) -> &Scalar {
+        &self.s
+    }
+}
+
+impl PrivateKey {
+    /// Returns the x-only public key used for Schnorr signatures.
+    pub fn x_only_public_key(&self) -> XOnlyPublicKey {
+        self.public_key().x_only()
+    }
+
+    /// Returns the key that signs key-path spends of the taproot output committing to this key
+    /// and no script tree: d + t, where d is the secret of the even-y point and t its tweak.
+    /// Its x-only public key is `self.x_only_public_key().tap_tweak()`.
+    /// Returns `Error::OutOfRange` if t is not below N or d + t is zero.
+    pub fn tap_tweak(&self) -> Result<PrivateKey, Error> {
+        let public_key = self.x_only_public_key();
+        let t = tap_tweak_hash(&public_key.to_bytes())?;
+        let mut d = if public_key.point() == self.public_key().point() {
+            self.secret().clone()
+        } else {
+            -self.secret()
+        };
+        let tweaked = PrivateKey::new(&d + &t);
+        d.zeroize();
+        tweaked
+    }
+
+    /// Signs `message` following BIP340, with `aux_rand` as auxiliary randomness.
+    /// Fresh random bytes protect against side channels; any fixed value, such as all zeros,
+    /// still gives a secure deterministic signature.
+    /// Returns `Error::InvalidNonce` if the derived nonce is zero, which BIP340 treats as a
+    /// failure (it needs a SHA-256 output that is a multiple of N).
+    pub fn sign_schnorr(
+        &self,
+        message: &[u8],
+        aux_rand: &[u8; 32],
+    ) -> Result<SchnorrSignature, Error> {
+        let public_key = self.x_only_public_key();
+        let public_bytes = public_key.to_bytes();
+        // d is the secret of the even-y point
+        let mut d = if public_key.point() == self.public_key().point() {
+            self.secret().clone()
+        } else {
+            -self.secret()
+        };
+
+        // t = d xor hash_aux(aux_rand), k' = hash_nonce(t || P.x || m) mod N
+        let mut t = d.to_be_bytes();
+        let aux_hash = tagged_hash("BIP0340/aux", &[aux_rand]);
+        t.iter_mut()
+            .zip(aux_hash)
+            .for_each(|(byte, mask)| *byte ^= mask);
+        let mut nonce = tagged_hash("BIP0340/nonce", &[&t, &public_bytes, message]);
+        t.zeroize();
+        let mut k = Scalar::reduce_be_bytes(&nonce);
+        nonce.zeroize();
+
+        let signature = match Point::mul_generator(&k) {
+            Point::Infinity => Err(Error::InvalidNonce),
+            Point::Coordinates { x: r, y } => {
+                // Use -k if R has odd y, selecting in constant time since R depends on k
+                let mut negated = -&k;
+                let mut k_even = Scalar::conditional_select(&k, &negated, y.is_odd_choice());
+                let c = challenge(&r.to_be_bytes(), &public_bytes, message);
+                let s = &k_even + &(&c * &d);
+                negated.zeroize();
+                k_even.zeroize();
+                Ok(SchnorrSignature { r, s })
+            }
+        };
+        d.zeroize();
+        k.zeroize();
+        signature
+    }
+}
+
+/// Computes the challenge hash_challenge(r || P.x || m) reduced mod N.
+fn challenge(r: &[u8; 32], public_key: &[u8; 32], message: &[u8]) -> Scalar {
+    Scalar::reduce_be_bytes(&tagged_hash("BIP0340/challenge", &[r, public_key, message]))
+}
+
+/// Computes the tweak hash_TapTweak(P.x) of an internal key without a script tree.
+/// Returns `Error::OutOfRange` if it is not below N.
+fn tap_tweak_hash(internal_key: &[u8; 32]) -> Result<Scalar, Error> {
+    Scalar::from_be_bytes(&tagged_hash("TapTweak", &[internal_key]))
+}
+
+/// Returns the x-coordinate of a finite point as 32 big-endian bytes.
+fn x_bytes(point: &Point) -> [u8; 32] {
+    match point {
+        Point::Coordinates { x, .. } => x.to_be_bytes(),
+        Point::Infinity => [0; 32],
+    }
+}
@@ -0,0 +1,359 @@
+use crate::error::Error;
+use crate::finite_fields::FieldElement;
+use crate::keys::PrivateKey;
+use crate::point::{G, Point};
+use crate::scalar::Scalar;
+use crate::schnorr::{SchnorrSignature, XOnlyPublicKey};
+use crate::test_util::bytes;
+use proptest::prelude::*;
+
+// A row of the BIP340 test-vectors.csv; signing rows have a secret key and aux_rand
+struct Vector {
+    secret: Option<&'static str>,
+    public_key: &'static str,
+    aux_rand: Option<&'static str>,
+    message: &'static str,
+    signature: &'static str,
+    valid: bool,
+}
+
+// Every row of the official BIP340 test vectors
+const VECTORS: [Vector; 19] = [
+    // Row 0
+    Vector {
+        secret: Some("0000000000000000000000000000000000000000000000000000000000000003"),
+        public_key: "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+        aux_rand: Some("0000000000000000000000000000000000000000000000000000000000000000"),
+        message: "0000000000000000000000000000000000000000000000000000000000000000",
+        signature: "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+        valid: true,
+    },
+    // Row 1
+    Vector {
+        secret: Some("B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF"),
+        public_key: "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        aux_rand: Some("0000000000000000000000000000000000000000000000000000000000000001"),
+        message: "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        signature: "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+        valid: true,
+    },
+    // Row 2
+    Vector {
+        secret: Some("C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9"),
+        public_key: "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+        aux_rand: Some("C87AA53824B4D7AE2EB035A2B5BBBCCC080E76CDC6D1692C4B0B62D798E6D906"),
+        message: "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+        signature: "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1BAB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7",
+        valid: true,
+    },
+    // Row 3: test fails if msg is reduced modulo p or n
+    Vector {
+        secret: Some("0B432B2677937381AEF05BB02A66ECD012773062CF3FA2549E44F58ED2401710"),
+        public_key: "25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517",
+        aux_rand: Some("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"),
+        message: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+        signature: "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3",
+        valid: true,
+    },
+    // Row 4
+    Vector {
+        secret: None,
+        public_key: "D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9",
+        aux_rand: None,
+        message: "4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703",
+        signature: "00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C6376AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4",
+        valid: true,
+    },
+    // Row 5: public key not on the curve
+    Vector {
+        secret: None,
+        public_key: "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34",
+        aux_rand: None,
+        message: "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        signature: "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+        valid: false,
+    },
+    // Row 6: has_even_y(R) is false
+    Vector {
+        secret: None,
+        public_key: "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        aux_rand: None,
+        message: "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        signature: "FFF97BD5755EEEA420453A14355235D382F6472F8568A18B2F057A14602975563CC27944640AC607CD107AE10923D9EF7A73C643E166BE5EBEAFA34B1AC553E2",
+        valid: false,
+    },
+    // Row 7: negated message
+    Vector {
+        secret: None,
+        public_key: "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        aux_rand: None,
+        message: "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        signature: "1FA62E331EDBC21C394792D2AB1100A7B432B013DF3F6FF4F99FCB33E0E1515F28890B3EDB6E7189B630448B515CE4F8622A954CFE545735AAEA5134FCCDB2BD",
+        valid: false,
+    },
+    // Row 8: negated s value
+    Vector {
+        secret: None,
+        public_key: "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        aux_rand: None,
+        message: "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        signature: "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769961764B3AA9B2FFCB6EF947B6887A226E8D7C93E00C5ED0C1834FF0D0C2E6DA6",
+        valid: false,
+    },
+    // Row 9: sG - eP is infinite
+    Vector {
+        secret: None,
+        public_key: "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        aux_rand: None,
+        message: "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        signature: "0000000000000000000000000000000000000000000000000000000000000000123DDA8328AF9C23A94C1FEECFD123BA4FB73476F0D594DCB65C6425BD186051",
+        valid: false,
+    },
+    // Row 10: sG - eP is infinite
+    Vector {
+        secret: None,
+        public_key: "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        aux_rand: None,
+        message: "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        signature: "00000000000000000000000000000000000000000000000000000000000000017615FBAF5AE28864013C099742DEADB4DBA87F11AC6754F93780D5A1837CF197",
+        valid: false,
+    },
+    // Row 11: sig[0:32] is not an X coordinate on the curve
+    Vector {
+        secret: None,
+        public_key: "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        aux_rand: None,
+        message: "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        signature: "4A298DACAE57395A15D0795DDBFD1DCB564DA82B0F269BC70A74F8220429BA1D69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+        valid: false,
+    },
+    // Row 12: sig[0:32] is equal to field size
+    Vector {
+        secret: None,
+        public_key: "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        aux_rand: None,
+        message: "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        signature: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+        valid: false,
+    },
+    // Row 13: sig[32:64] is equal to curve order
+    Vector {
+        secret: None,
+        public_key: "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        aux_rand: None,
+        message: "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        signature: "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+        valid: false,
+    },
+    // Row 14: public key exceeds the field size
+    Vector {
+        secret: None,
+        public_key: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+        aux_rand: None,
+        message: "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+        signature: "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+        valid: false,
+    },
+    // Row 15: message of size 0
+    Vector {
+        secret: Some("0340034003400340034003400340034003400340034003400340034003400340"),
+        public_key: "778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117",
+        aux_rand: Some("0000000000000000000000000000000000000000000000000000000000000000"),
+        message: "",
+        signature: "71535DB165ECD9FBBC046E5FFAEA61186BB6AD436732FCCC25291A55895464CF6069CE26BF03466228F19A3A62DB8A649F2D560FAC652827D1AF0574E427AB63",
+        valid: true,
+    },
+    // Row 16: message of size 1
+    Vector {
+        secret: Some("0340034003400340034003400340034003400340034003400340034003400340"),
+        public_key: "778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117",
+        aux_rand: Some("0000000000000000000000000000000000000000000000000000000000000000"),
+        message: "11",
+        signature: "08A20A0AFEF64124649232E0693C583AB1B9934AE63B4C3511F3AE1134C6A303EA3173BFEA6683BD101FA5AA5DBC1996FE7CACFC5A577D33EC14564CEC2BACBF",
+        valid: true,
+    },
+    // Row 17: message of size 17
+    Vector {
+        secret: Some("0340034003400340034003400340034003400340034003400340034003400340"),
+        public_key: "778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117",
+        aux_rand: Some("0000000000000000000000000000000000000000000000000000000000000000"),
+        message: "0102030405060708090A0B0C0D0E0F1011",
+        signature: "5130F39A4059B43BC7CAC09A19ECE52B5D8699D1A71E3C52DA9AFDB6B50AC370C4A482B77BF960F8681540E25B6771ECE1E5A37FD80E5A51897C5566A97EA5A5",
+        valid: true,
+    },
+    // Row 18: message of size 100
+    Vector {
+        secret: Some("0340034003400340034003400340034003400340034003400340034003400340"),
+        public_key: "778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117",
+        aux_rand: Some("0000000000000000000000000000000000000000000000000000000000000000"),
+        message: "99999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999",
+        signature: "403B12B0D8555A344175EA7EC746566303321E5DBFA8BE6F091635163ECA79A8585ED3E3170807E7C03B720FC54C7B23897FCBA0E9D0B4A06894CFD249F22367",
+        valid: true,
+    },
+];
+
+//------------------------------
+// BIP340 Vector Tests
+//------------------------------
+
+#[test]
+fn test_sign_vectors() {
+    // Test that each signing row gives the listed public key and signature.
+    for (row, vector) in VECTORS.iter().enumerate() {
+        let (Some(secret), Some(aux_rand)) = (vector.secret, vector.aux_rand) else {
+            continue;
+        };
+        let key = PrivateKey::from_bytes(&bytes(secret)).unwrap();
+        let message = crate::hex::decode(vector.message).unwrap();
+        assert_eq!(
+            key.x_only_public_key().to_bytes(),
+            bytes(vector.public_key),
+            "row {row}"
+        );
+        let signature = key.sign_schnorr(&message, &bytes(aux_rand)).unwrap();
+        assert_eq!(signature.to_bytes(), bytes(vector.signature), "row {row}");
+    }
+}
+
+#[test]
+fn test_verify_vectors() {
+    // Test that every row verifies to its listed result; a key or signature that does not
+    // parse counts as a failed verification.
+    for (row, vector) in VECTORS.iter().enumerate() {
+        let message = crate::hex::decode(vector.message).unwrap();
+        let public_key = XOnlyPublicKey::from_bytes(&bytes(vector.public_key));
+        let signature = SchnorrSignature::from_bytes(&bytes(vector.signature));
+        let valid = match (public_key, signature) {
+            (Ok(public_key), Ok(signature)) => public_key.verify_schnorr(&message, &signature),
+            _ => false,
+        };
+        assert_eq!(valid, vector.valid, "row {row}");
+    }
+}
+
+#[test]
+fn test_invalid_encodings() {
+    // Test the parse errors behind rows 5, 12, 13 and 14.
+    assert_eq!(
+        XOnlyPublicKey::from_bytes(&bytes(VECTORS[5].public_key)),
+        Err(Error::NotOnCurve)
+    );
+    assert_eq!(
+        SchnorrSignature::from_bytes(&bytes(VECTORS[12].signature)),
+        Err(Error::OutOfRange)
+    );
+    assert_eq!(
+        SchnorrSignature::from_bytes(&bytes(VECTORS[13].signature)),
+        Err(Error::OutOfRange)
+    );
+    assert_eq!(
+        XOnlyPublicKey::from_bytes(&bytes(VECTORS[14].public_key)),
+        Err(Error::OutOfRange)
+    );
+}
+
+//------------------------------
+// X-Only Key Tests
+//------------------------------
+
+#[test]
+fn test_lift_x_even_y() {
+    // Test that lift_x picks the even y: the generator's y is even, the row 3 key's is odd.
+    let Point::Coordinates { x, .. } = &G else {
+        unreachable!()
+    };
+    assert_eq!(XOnlyPublicKey::lift_x(x).unwrap().point(), &G);
+
+    let key = PrivateKey::from_bytes(&bytes(VECTORS[3].secret.unwrap())).unwrap();
+    let Point::Coordinates { x, y } = key.public_key().point() else {
+        unreachable!()
+    };
+    assert!(y.is_odd());
+    let x_only = key.x_only_public_key();
+    assert_eq!(x_only, XOnlyPublicKey::lift_x(x).unwrap());
+    assert_eq!(
+        x_only.point(),
+        &Point::Coordinates {
+            x: x.clone(),
+            y: -y
+        }
+    );
+}
+
+#[test]
+fn test_lift_x_not_on_curve() {
+    // Test that x = 5 is rejected, since 5^3 + 7 = 132 is not a square mod p.
+    let x = FieldElement::from_hex("05").unwrap();
+    assert_eq!(XOnlyPublicKey::lift_x(&x), Err(Error::NotOnCurve));
+}
+
+#[test]
+fn test_tap_tweak_vector() {
+    // Test the BIP341 wallet vector of an internal key without a script tree.
+    let internal_key = XOnlyPublicKey::from_bytes(&bytes(
+        "d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d",
+    ))
+    .unwrap();
+    assert_eq!(
+        internal_key.tap_tweak().unwrap().to_bytes(),
+        bytes("53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343")
+    );
+}
+
+#[test]
+fn test_private_tap_tweak_matches_public() {
+    // Test that the tweaked private key signs for the tweaked output key, for keys with even
+    // and with odd y.
+    for vector in &VECTORS[..4] {
+        let key = PrivateKey::from_bytes(&bytes(vector.secret.unwrap())).unwrap();
+        let output_key = key.x_only_public_key().tap_tweak().unwrap();
+        let tweaked = key.tap_tweak().unwrap();
+        assert_eq!(tweaked.x_only_public_key(), output_key);
+        let signature = tweaked.sign_schnorr(b"taproot", &[0; 32]).unwrap();
+        assert!(output_key.verify_schnorr(b"taproot", &signature));
+    }
+}
+
+//------------------------------
+// Signature Tests
+//------------------------------
+
+#[test]
+fn test_verify_rejects_tampering() {
+    // Test that changing the message, s or the key makes verification fail.
+    let vector = &VECTORS[1];
+    let public_key = XOnlyPublicKey::from_bytes(&bytes(vector.public_key)).unwrap();
+    let message = crate::hex::decode(vector.message).unwrap();
+    let signature = SchnorrSignature::from_bytes(&bytes(vector.signature)).unwrap();
+    assert!(public_key.verify_schnorr(&message, &signature));
+    assert!(!public_key.verify_schnorr(&message[1..], &signature));
+
+    let mut tampered = signature.to_bytes();
+    tampered[63] ^= 1;
+    let tampered = SchnorrSignature::from_bytes(&tampered).unwrap();
+    assert!(!public_key.verify_schnorr(&message, &tampered));
+
+    let other = XOnlyPublicKey::from_bytes(&bytes(VECTORS[0].public_key)).unwrap();
+    assert!(!other.verify_schnorr(&message, &signature));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn prop_sign_verify_round_trip(
+        e in any::<[u8; 32]>(),
+        message in proptest::collection::vec(any::<u8>(), 0..64),
+        aux_rand in any::<[u8; 32]>(),
+    ) {
+        // Test that signatures with arbitrary keys, messages and aux_rand verify and
+        // survive a byte round trip.
+        let secret = Scalar::reduce_be_bytes(&e);
+        prop_assume!(!secret.is_zero());
+        let key = PrivateKey::new(secret).unwrap();
+        let signature = key.sign_schnorr(&message, &aux_rand).unwrap();
+        let parsed = SchnorrSignature::from_bytes(&signature.to_bytes()).unwrap();
+        prop_assert_eq!(&parsed, &signature);
+        prop_assert!(key.x_only_public_key().verify_schnorr(&message, &parsed));
+    }
+}
@@ -0,0 +1,338 @@
+// A small Bitcoin script interpreter for segwit version 0 spends (BIP141), enough to check
+// P2WPKH and P2WSH witnesses whose scripts use pushes, stack and hash operations, and
+// single-key or multisig signature checks.
+// Besides the consensus rules (script, element and stack sizes, the opcode count) it applies the
+// standardness rules that BIP322 requires of proofs: MINIMALDATA, strict DER, low-S, compressed
+// keys, NULLFAIL, NULLDUMMY and a clean stack.
+// Opcodes outside that set (flow control, arithmetic, time locks, OP_CODESEPARATOR) make the
+// script fail, so a script this interpreter cannot evaluate is never accepted.
+// Only SIGHASH_ALL signatures are accepted.
+
+use crate::ecdsa::Signature;
+use crate::hash::{hash160, hash256, sha256};
+use crate::keys::PublicKey;
+use crate::scalar::Scalar;
+use crate::transaction::SIGHASH_ALL;
+use alloc::vec;
+use alloc::vec::Vec;
+
+pub(crate) const OP_0: u8 = 0x00;
+const OP_PUSHDATA1: u8 = 0x4c;
+const OP_PUSHDATA2: u8 = 0x4d;
+const OP_PUSHDATA4: u8 = 0x4e;
+const OP_1NEGATE: u8 = 0x4f;
+pub(crate) const OP_1: u8 = 0x51;
+const OP_16: u8 = 0x60;
+const OP_NOP: u8 = 0x61;
+const OP_VERIFY: u8 = 0x69;
+pub(crate) const OP_RETURN: u8 = 0x6a;
+const OP_DROP: u8 = 0x75;
+pub(crate) const OP_DUP: u8 = 0x76;
+pub(crate) const OP_EQUAL: u8 = 0x87;
+pub(crate) const OP_EQUALVERIFY: u8 = 0x88;
+const OP_SHA256: u8 = 0xa8;
+pub(crate) const OP_HASH160: u8 = 0xa9;
+const OP_HASH256: u8 = 0xaa;
+pub(crate) const OP_CHECKSIG: u8 = 0xac;
+const OP_CHECKSIGVERIFY: u8 = 0xad;
+pub(crate) const OP_CHECKMULTISIG: u8 = 0xae;
+const OP_CHECKMULTISIGVERIFY: u8 = 0xaf;
+
+// Consensus limits on script and stack element sizes, on keys in a multisig, on opcodes
+// above OP_16 in a script (each multisig key counts as one more) and on stack items
+const MAX_SCRIPT_SIZE: usize = 10_000;
+const MAX_ELEMENT_SIZE: usize = 520;
+const MAX_MULTISIG_KEYS: usize = 20;
+const MAX_OPS_PER_SCRIPT: usize = 201;
+const MAX_STACK_SIZE: usize = 1000;
+
+/// Appends a push of `data` with the smallest push opcode.
+pub(crate) fn push_data(script: &mut Vec<u8>, data: &[u8]) {
+    match data.len() {
+        0..0x4c => script.push(data.len() as u8),
+        0x4c..=0xff => script.extend_from_slice(&[OP_PUSHDATA1, data.len() as u8]),
+        0x100..=0xffff => {
+            script.push(OP_PUSHDATA2);
+            script.extend_from_slice(&(data.len() as u16).to_le_bytes());
+        }
+        _ => {
+            script.push(OP_PUSHDATA4);
+            script.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        }
+    }
+    script.extend_from_slice(data);
+}
+
+/// Builds the P2PKH script OP_DUP OP_HASH160 <hash> OP_EQUALVERIFY OP_CHECKSIG, which is
+/// also the script code of a P2WPKH input.
+pub(crate) fn p2pkh_script(pubkey_hash: &[u8; 20]) -> Vec<u8> {
+    let mut script = vec![OP_DUP, OP_HASH160];
+    push_data(&mut script, pubkey_hash);
+    script.extend_from_slice(&[OP_EQUALVERIFY, OP_CHECKSIG]);
+    script
+}
+
+/// Splits a script into its instructions: `Ok(data)` for pushes and `Err(opcode)` for other
+/// opcodes. Returns `None` if a push runs past the end of the script or, following
+/// MINIMALDATA, does not use the shortest encoding of its data.
+pub(crate) fn instructions(script: &[u8]) -> Option<Vec<Result<&[u8], u8>>> {
+    let mut instructions = Vec::new();
+    let mut pos = 0;
+    while pos < script.len() {
+        let opcode = script[pos];
+        pos += 1;
+        let len = match opcode {
+            0x01..OP_PUSHDATA1 => opcode as usize,
+            OP_PUSHDATA1 => {
+                pos += 1;
+                *script.get(pos - 1)? as usize
+            }
+            OP_PUSHDATA2 => {
+                pos += 2;
+                u16::from_le_bytes(script.get(pos - 2..pos)?.try_into().unwrap()) as usize
+            }
+            OP_PUSHDATA4 => {
+                pos += 4;
+                u32::from_le_bytes(script.get(pos - 4..pos)?.try_into().unwrap()) as usize
+            }
+            _ => {
+                instructions.push(if opcode == OP_0 {
+                    Ok(&[][..])
+                } else {
+                    Err(opcode)
+                });
+                continue;
+            }
+        };
+        let data = script.get(pos..pos.checked_add(len)?)?;
+        pos += len;
+        if !is_minimal_push(opcode, data) {
+            return None;
+        }
+        instructions.push(Ok(data));
+    }
+    Some(instructions)
+}
+
+/// Returns true if no shorter push encodes `data`: OP_1NEGATE and OP_1 to OP_16 for the single
+/// bytes 0x81 and 1 to 16, then a direct push up to 75 bytes, OP_PUSHDATA1 and OP_PUSHDATA2.
+fn is_minimal_push(opcode: u8, data: &[u8]) -> bool {
+    match data {
+        [0x01..=0x10 | 0x81] => false,
+        _ => match data.len() {
+            0..0x4c => opcode as usize == data.len(),
+            0x4c..=0xff => opcode == OP_PUSHDATA1,
+            0x100..=0xffff => opcode == OP_PUSHDATA2,
+            _ => true,
+        },
+    }
+}
+
+/// Returns the witness version and program if the script is a segwit output script:
+/// a version opcode (OP_0 or OP_1 to OP_16) followed by a single push of 2 to 40 bytes.
+pub(crate) fn witness_program(script: &[u8]) -> Option<(u8, &[u8])> {
+    let version = match *script.first()? {
+        OP_0 => 0,
+        opcode @ OP_1..=OP_16 => opcode - OP_1 + 1,
+        _ => return None,
+    };
+    if !(4..=42).contains(&script.len()) || script[1] as usize != script.len() - 2 {
+        return None;
+    }
+    Some((version, &script[2..]))
+}
+
+/// Verifies a segwit version 0 spend: the witness must satisfy the program, with `sighash`
+/// computing the SIGHASH_ALL signature hash for a given script code.
+/// A 20-byte program (P2WPKH) takes a signature and a key; a 32-byte program (P2WSH) takes the
+/// script's inputs followed by the script, whose SHA-256 must equal the program.
+pub(crate) fn verify_witness_v0(
+    program: &[u8],
+    witness: &[Vec<u8>],
+    sighash: &dyn Fn(&[u8]) -> [u8; 32],
+) -> bool {
+    let (script, stack) = match program.len() {
+        20 => {
+            if witness.len() != 2 {
+                return false;
+            }
+            (p2pkh_script(program.try_into().unwrap()), witness.to_vec())
+        }
+        32 => match witness.split_last() {
+            Some((script, stack)) if sha256(script) == program => (script.clone(), stack.to_vec()),
+            _ => return false,
+        },
+        _ => return false,
+    };
+    if stack.iter().any(|item| item.len() > MAX_ELEMENT_SIZE) {
+        return false;
+    }
+    let mut stack = stack;
+    execute(&script, &mut stack, sighash).is_some() && stack.len() == 1 && cast_to_bool(&stack[0])
+}
+
+/// Runs a script on the stack. Returns `None` if the script fails.
+pub(crate) fn execute(
+    script: &[u8],
+    stack: &mut Vec<Vec<u8>>,
+    sighash: &dyn Fn(&[u8]) -> [u8; 32],
+) -> Option<()> {
+    if script.len() > MAX_SCRIPT_SIZE {
+        return None;
+    }
+    let mut op_count = 0;
+    for instruction in instructions(script)? {
+        let opcode = match instruction {
+            Ok(data) if data.len() <= MAX_ELEMENT_SIZE => {
+                stack.push(data.to_vec());
+                if stack.len() > MAX_STACK_SIZE {
+                    return None;
+                }
+                continue;
+            }
+            Ok(_) => return None,
+            Err(opcode) => opcode,
+        };
+        if opcode > OP_16 {
+            op_count += 1;
+        }
+        if matches!(opcode, OP_CHECKMULTISIG | OP_CHECKMULTISIGVERIFY) {
+            op_count += small_int(stack.last()?)?;
+        }
+        if op_count > MAX_OPS_PER_SCRIPT {
+            return None;
+        }
+        match opcode {
+            OP_1NEGATE => stack.push(vec![0x81]),
+            OP_1..=OP_16 => stack.push(vec![opcode - OP_1 + 1]),
+            OP_NOP => {}
+            OP_VERIFY => verify(stack.pop()?)?,
+            OP_DROP => {
+                stack.pop()?;
+            }
+            OP_DUP => stack.push(stack.last()?.clone()),
+            OP_EQUAL | OP_EQUALVERIFY => {
+                let equal = stack.pop()? == stack.pop()?;
+                push_bool(stack, equal, opcode == OP_EQUALVERIFY)?;
+            }
+            OP_SHA256 => {
+                let top = stack.pop()?;
+                stack.push(sha256(&top).to_vec());
+            }
+            OP_HASH160 => {
+                let top = stack.pop()?;
+                stack.push(hash160(&top).to_vec());
+            }
+            OP_HASH256 => {
+                let top = stack.pop()?;
+                stack.push(hash256(&top).to_vec());
+            }
+            OP_CHECKSIG | OP_CHECKSIGVERIFY => {
+                let pubkey = stack.pop()?;
+                let signature = stack.pop()?;
+                let valid = check_signature(&signature, &pubkey, script, sighash)?;
+                push_bool(stack, valid, opcode == OP_CHECKSIGVERIFY)?;
+            }
+            OP_CHECKMULTISIG | OP_CHECKMULTISIGVERIFY => {
+                let valid = check_multisig(stack, script, sighash)?;
+                push_bool(stack, valid, opcode == OP_CHECKMULTISIGVERIFY)?;
+            }
+            _ => return None,
+        }
+        if stack.len() > MAX_STACK_SIZE {
+            return None;
+        }
+    }
+    Some(())
+}
+
+/// Returns the truth value of a stack element: false for empty, all zeros, or all zeros with
+/// a final 0x80 (negative zero).
+fn cast_to_bool(element: &[u8]) -> bool {
+    match element.split_last() {
+        None => false,
+        Some((&last, rest)) => rest.iter().any(|&byte| byte != 0) || (last & 0x7f) != 0,
+    }
+}
+
+fn verify(element: Vec<u8>) -> Option<()> {
+    cast_to_bool(&element).then_some(())
+}
+
+/// Pushes the result of a comparison, or for the VERIFY variants fails unless it is true.
+fn push_bool(stack: &mut Vec<Vec<u8>>, value: bool, verify_only: bool) -> Option<()> {
+    match (value, verify_only) {
+        (_, false) => stack.push(if value { vec![1] } else { Vec::new() }),
+        (true, true) => {}
+        (false, true) => return None,
+    }
+    Some(())
+}
+
+/// Checks one signature (DER plus the hash type byte) against a key.
+/// Returns `Some(false)` for an empty signature and `None` (script failure) for any other
+/// signature that does not verify, following NULLFAIL, or for an unacceptable key.
+fn check_signature(
+    signature: &[u8],
+    pubkey: &[u8],
+    script_code: &[u8],
+    sighash: &dyn Fn(&[u8]) -> [u8; 32],
+) -> Option<bool> {
+    // Segwit version 0 only allows compressed keys
+    if pubkey.len() != 33 {
+        return None;
+    }
+    let public_key = PublicKey::from_sec(pubkey).ok()?;
+    let (&hash_type, der) = match signature.split_last() {
+        None => return Some(false),
+        Some(split) => split,
+    };
+    if hash_type != SIGHASH_ALL {
+        return None;
+    }
+    let signature = Signature::from_der(der).ok()?;
+    let z = Scalar::reduce_be_bytes(&sighash(script_code));
+    public_key.verify_strict(&z, &signature).then_some(true)
+}
+
+/// Runs OP_CHECKMULTISIG on the stack: <dummy> <sig>... <m> <key>... <n>.
+/// Each signature must match a later key than the one before it; the dummy must be empty.
+fn check_multisig(
+    stack: &mut Vec<Vec<u8>>,
+    script_code: &[u8],
+    sighash: &dyn Fn(&[u8]) -> [u8; 32],
+) -> Option<bool> {
+    let key_count = small_int(&stack.pop()?).filter(|&n| n <= MAX_MULTISIG_KEYS)?;
+    let keys = stack.split_off(stack.len().checked_sub(key_count)?);
+    let signature_count = small_int(&stack.pop()?).filter(|&m| m <= key_count)?;
+    let signatures = stack.split_off(stack.len().checked_sub(signature_count)?);
+    if !stack.pop()?.is_empty() {
+        return None;
+    }
+
+    // Keys and signatures are in the same order, so scan the keys once
+    let mut keys = keys.iter();
+    for signature in &signatures {
+        let mut matched = false;
+        for pubkey in keys.by_ref() {
+            if check_signature(signature, pubkey, script_code, sighash).unwrap_or(false) {
+                matched = true;
+                break;
+            }
+        }
+        if !matched {
+            // NULLFAIL: a failed check is only allowed if every signature is empty
+            return signatures.iter().all(Vec::is_empty).then_some(false);
+        }
+    }
+    Some(true)
+}
+
+/// Decodes a minimally encoded script number from 0 to 127.
+fn small_int(element: &[u8]) -> Option<usize> {
+    match element {
+        [] => Some(0),
+        [n @ 0x01..=0x7f] => Some(*n as usize),
+        _ => None,
+    }
+}
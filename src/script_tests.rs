@@ -0,0 +1,161 @@
+use crate::hash::sha256;
+use crate::script::{execute, instructions, push_data, verify_witness_v0, witness_program};
+
+// Helper function to verify a P2WSH witness whose script never checks signatures
+fn run(witness_script: &[u8], inputs: &[&[u8]]) -> bool {
+    let mut witness: Vec<Vec<u8>> = inputs.iter().map(|item| item.to_vec()).collect();
+    witness.push(witness_script.to_vec());
+    verify_witness_v0(&sha256(witness_script), &witness, &|_| [0; 32])
+}
+
+//------------------------------
+// Parsing Tests
+//------------------------------
+
+#[test]
+fn test_push_data_sizes() {
+    // Test that pushes use a direct length up to 75 bytes, then OP_PUSHDATA1 and OP_PUSHDATA2.
+    for (len, prefix) in [
+        (0, vec![0x00]),
+        (75, vec![75]),
+        (76, vec![0x4c, 76]),
+        (256, vec![0x4d, 0x00, 0x01]),
+    ] {
+        let mut script = Vec::new();
+        push_data(&mut script, &vec![0xab; len]);
+        assert_eq!(script[..prefix.len()], prefix[..]);
+        assert_eq!(
+            instructions(&script).unwrap(),
+            vec![Ok(&vec![0xab; len][..])]
+        );
+    }
+}
+
+#[test]
+fn test_instructions_truncated_push() {
+    // Test that a push running past the end of the script is rejected.
+    assert_eq!(instructions(&[0x02, 0xaa]), None);
+    assert_eq!(instructions(&[0x4c]), None);
+    assert_eq!(
+        instructions(&[0x76, 0x00]).unwrap(),
+        vec![Err(0x76), Ok(&[][..])]
+    );
+}
+
+#[test]
+fn test_instructions_minimal_data() {
+    // Test that a push with a longer encoding than its data needs is rejected (MINIMALDATA).
+    assert_eq!(instructions(&[0x01, 0x05]), None);
+    assert_eq!(instructions(&[0x01, 0x81]), None);
+    assert_eq!(instructions(&[0x4c, 0x00]), None);
+    assert_eq!(instructions(&[0x4c, 0x01, 0xaa]), None);
+    assert_eq!(instructions(&[0x4d, 0xff, 0x00]), None);
+    assert_eq!(instructions(&[0x01, 0x00]).unwrap(), vec![Ok(&[0x00][..])]);
+    assert_eq!(instructions(&[0x01, 0x11]).unwrap(), vec![Ok(&[0x11][..])]);
+    assert!(run(&[0x55, 0x55, 0x87], &[]));
+    assert!(!run(&[0x01, 0x05, 0x55, 0x87], &[]));
+}
+
+#[test]
+fn test_witness_program() {
+    // Test that only a version opcode followed by one direct push of 2 to 40 bytes is a program.
+    let mut p2wpkh = vec![0x00];
+    push_data(&mut p2wpkh, &[7; 20]);
+    assert_eq!(witness_program(&p2wpkh), Some((0, &[7u8; 20][..])));
+    assert_eq!(
+        witness_program(&[0x51, 0x02, 0xaa, 0xbb]),
+        Some((1, &[0xaa, 0xbb][..]))
+    );
+    assert_eq!(witness_program(&[0x00]), None);
+    assert_eq!(witness_program(&[0x00, 0x01, 0xaa]), None);
+    assert_eq!(witness_program(&[0x61, 0x02, 0xaa, 0xbb]), None);
+    assert_eq!(witness_program(&[0x00, 0x03, 0xaa, 0xbb]), None);
+}
+
+//------------------------------
+// Execution Tests
+//------------------------------
+
+#[test]
+fn test_hash_lock() {
+    // Test the script OP_SHA256 <hash> OP_EQUAL, which needs the preimage.
+    let mut script = vec![0xa8];
+    push_data(&mut script, &sha256(b"secret"));
+    script.push(0x87);
+    assert!(run(&script, &[b"secret"]));
+    assert!(!run(&script, &[b"guess"]));
+    assert!(!run(&script, &[]));
+}
+
+#[test]
+fn test_clean_stack_and_truth() {
+    // Test that exactly one true element must remain, where zero and negative zero are false.
+    assert!(run(&[0x51], &[]));
+    assert!(!run(&[0x51, 0x51], &[]));
+    assert!(!run(&[0x00], &[]));
+    assert!(!run(&[], &[&[0x00, 0x80]]));
+    assert!(run(&[], &[&[0x00, 0x01]]));
+    assert!(run(&[0x75], &[&[1], &[1]]));
+}
+
+#[test]
+fn test_unsupported_opcodes_fail() {
+    // Test that opcodes outside the supported set, such as OP_IF and OP_RETURN, fail the script.
+    assert!(!run(&[0x63, 0x51, 0x68], &[&[1]]));
+    assert!(!run(&[0x51, 0x6a], &[]));
+}
+
+#[test]
+fn test_verify_consumes_top() {
+    // Test that OP_VERIFY pops a true value and fails the script on a false one.
+    assert!(run(&[0x51, 0x69, 0x51], &[]));
+    assert!(!run(&[0x00, 0x69, 0x51], &[]));
+}
+
+#[test]
+fn test_witness_v0_shape() {
+    // Test that P2WPKH needs exactly two witness items and P2WSH a script matching the program.
+    let check = |_: &[u8]| [0; 32];
+    assert!(!verify_witness_v0(&[0; 20], &[vec![1]], &check));
+    assert!(!verify_witness_v0(&[0; 32], &[vec![0x51]], &check));
+    assert!(!verify_witness_v0(&[0; 32], &[], &check));
+    assert!(!verify_witness_v0(&[0; 16], &[vec![0x51]], &check));
+}
+
+#[test]
+fn test_op_count_limit() {
+    // Test that at most 201 opcodes above OP_16 may appear in a script.
+    let mut script = vec![0x61; 201];
+    script.push(0x51);
+    assert!(run(&script, &[]));
+    script.insert(0, 0x61);
+    assert!(!run(&script, &[]));
+}
+
+#[test]
+fn test_op_count_includes_multisig_keys() {
+    // Test that each key of an OP_CHECKMULTISIG counts towards the opcode limit.
+    let multisig = |nops: usize| {
+        let mut script = vec![0x61; nops];
+        script.push(0x00);
+        for _ in 0..20 {
+            push_data(&mut script, &[0x02; 33]);
+        }
+        script.extend_from_slice(&[0x01, 20, 0xae]);
+        script
+    };
+    assert!(run(&multisig(180), &[&[]]));
+    assert!(!run(&multisig(181), &[&[]]));
+}
+
+#[test]
+fn test_stack_size_limit() {
+    // Test that the stack may hold at most 1000 items.
+    let check = |_: &[u8]| [0; 32];
+    let mut stack = vec![Vec::new(); 999];
+    assert!(execute(&[0x51], &mut stack, &check).is_some());
+    assert!(execute(&[0x51], &mut stack, &check).is_none());
+    let mut stack = vec![Vec::new(); 999];
+    assert!(execute(&[0x76], &mut stack, &check).is_some());
+    assert!(execute(&[0x76], &mut stack, &check).is_none());
+}
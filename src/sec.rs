@@ -0,0 +1,70 @@
+// SEC1 point encoding (Standards for Efficient Cryptography, section 2.3.3 and 2.3.4).
+// Uncompressed: 0x04 || x || y (65 bytes). Compressed: 0x02 or 0x03 || x (33 bytes), where the
+// prefix records the parity of y. The point at infinity is the single byte 0x00.
+// Coordinates are written as 32 big-endian bytes, matching the 256-bit fields used here.
+
+use crate::curve::{Curve, CurvePoint, curve_rhs};
+use crate::error::Error;
+use crate::finite_fields::Fp;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+impl<C: Curve> CurvePoint<C> {
+    /// Serializes the point in SEC1 format: 33 bytes if `compressed`, 65 bytes otherwise.
+    /// The point at infinity is encoded as the single byte 0x00.
+    pub fn to_sec(&self, compressed: bool) -> Vec<u8> {
+        let (x, y) = match self {
+            CurvePoint::Infinity => return vec![0x00],
+            CurvePoint::Coordinates { x, y } => (x, y),
+        };
+        let mut bytes = Vec::with_capacity(65);
+        if compressed {
+            bytes.push(if y.is_odd() { 0x03 } else { 0x02 });
+            bytes.extend_from_slice(&x.to_be_bytes());
+        } else {
+            bytes.push(0x04);
+            bytes.extend_from_slice(&x.to_be_bytes());
+            bytes.extend_from_slice(&y.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Parses a compressed or uncompressed SEC1 encoding.
+    /// Compressed points are decompressed by solving y^2 = x^3 + ax + b with a square root and
+    /// picking the root whose parity matches the prefix. Rejects the point at infinity, hybrid
+    /// encodings and coordinates that are not below p.
+    pub fn from_sec(bytes: &[u8]) -> Result<Self, Error> {
+        let (&prefix, body) = bytes.split_first().ok_or(Error::InvalidLength(0))?;
+        match (prefix, body.len()) {
+            (0x00, _) => Err(Error::PointAtInfinity),
+            (0x04, 64) => {
+                let x = Fp::from_be_bytes(body[..32].try_into().unwrap())?;
+                let y = Fp::from_be_bytes(body[32..].try_into().unwrap())?;
+                CurvePoint::new(Some(x), Some(y))
+            }
+            (0x02 | 0x03, 32) => {
+                let x = Fp::<C::Field>::from_be_bytes(body.try_into().unwrap())?;
+                let y = curve_rhs::<C>(&x).sqrt().ok_or(Error::NotOnCurve)?;
+                let y = if y.is_odd() == (prefix == 0x03) {
+                    y
+                } else {
+                    -y
+                };
+                Ok(CurvePoint::Coordinates { x, y })
+            }
+            (0x02..=0x04, _) => Err(Error::InvalidLength(bytes.len())),
+            _ => Err(Error::InvalidPrefix(prefix)),
+        }
+    }
+
+    /// Returns the SEC1 encoding as lowercase hex (66 digits if `compressed`, 130 otherwise).
+    pub fn to_hex(&self, compressed: bool) -> String {
+        crate::hex::encode(&self.to_sec(compressed))
+    }
+
+    /// Parses a hex-encoded SEC1 point, e.g. a public key as printed by Bitcoin tools.
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        Self::from_sec(&crate::hex::decode(hex)?)
+    }
+}
@@ -0,0 +1,162 @@
+use crate::curve::{CurvePoint, Secp256r1};
+use crate::error::Error;
+use crate::point::{G, Point};
+use crate::test_util::hex;
+use num_bigint::BigInt;
+use proptest::prelude::*;
+
+// Helper function computing k * G for a small integer k
+fn mul_g(k: BigInt) -> Point {
+    &G * &k
+}
+
+//------------------------------
+// Serialization Vector Tests
+//------------------------------
+
+#[test]
+fn test_to_sec_uncompressed() {
+    // Test the book's uncompressed SEC vectors for the secrets 5000, 2018^5 and 0xdeadbeef12345.
+    let cases = [
+        (
+            BigInt::from(5000),
+            "04ffe558e388852f0120e46af2d1b370f85854a8eb0841811ece0e3e03d282d57c315dc72890a4f10a1481c031b03b351b0dc79901ca18a00cf009dbdb157a1d10",
+        ),
+        (
+            BigInt::from(2018).pow(5),
+            "04027f3da1918455e03c46f659266a1bb5204e959db7364d2f473bdf8f0a13cc9dff87647fd023c13b4a4994f17691895806e1b40b57f4fd22581a4f46851f3b06",
+        ),
+        (
+            BigInt::from(0xdeadbeef12345u64),
+            "04d90cd625ee87dd38656dd95cf79f65f60f7273b67d3096e68bd81e4f5342691f842efa762fd59961d0e99803c61edba8b3e3f7dc3a341836f97733aebf987121",
+        ),
+    ];
+    for (secret, expected) in cases {
+        let point = mul_g(secret);
+        assert_eq!(point.to_sec(false), hex(expected));
+        assert_eq!(Point::from_sec(&hex(expected)).unwrap(), point);
+    }
+}
+
+#[test]
+fn test_to_sec_compressed() {
+    // Test the book's compressed SEC vectors for the secrets 5001, 2019^5 and 0xdeadbeef54321.
+    let cases = [
+        (
+            BigInt::from(5001),
+            "0357a4f368868a8a6d572991e484e664810ff14c05c0fa023275251151fe0e53d1",
+        ),
+        (
+            BigInt::from(2019).pow(5),
+            "02933ec2d2b111b92737ec12f1c5d20f3233a0ad21cd8b36d0bca7a0cfa5cb8701",
+        ),
+        (
+            BigInt::from(0xdeadbeef54321u64),
+            "0296be5b1292f6c856b3c5654e886fc13511462059089cdf9c479623bfcbe77690",
+        ),
+    ];
+    for (secret, expected) in cases {
+        let point = mul_g(secret);
+        assert_eq!(point.to_sec(true), hex(expected));
+        assert_eq!(Point::from_sec(&hex(expected)).unwrap(), point);
+    }
+}
+
+#[test]
+fn test_generator_public_key() {
+    // Test the well-known public key of the private key 1, in both encodings.
+    let compressed = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    let uncompressed = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+    assert_eq!(G.to_sec(true), hex(compressed));
+    assert_eq!(G.to_sec(false), hex(uncompressed));
+}
+
+#[test]
+fn test_infinity_encoding() {
+    // Test that infinity serializes to 0x00 and is rejected when parsing.
+    assert_eq!(Point::Infinity.to_sec(true), vec![0x00]);
+    assert_eq!(Point::from_sec(&[0x00]), Err(Error::PointAtInfinity));
+}
+
+#[test]
+fn test_p256_round_trip() {
+    // Test that compression works on secp256r1, whose curve equation has a = -3.
+    let g = CurvePoint::<Secp256r1>::generator();
+    let point = &g * &BigInt::from(7);
+    for compressed in [true, false] {
+        let bytes = point.to_sec(compressed);
+        assert_eq!(CurvePoint::<Secp256r1>::from_sec(&bytes).unwrap(), point);
+    }
+}
+
+//------------------------
+// Parsing Error Tests
+//------------------------
+
+#[test]
+fn test_from_sec_invalid_prefix() {
+    // Test that hybrid (0x06/0x07) and unknown prefixes are rejected.
+    for prefix in [0x01, 0x05, 0x06, 0x07, 0xff] {
+        let mut bytes = G.to_sec(false);
+        bytes[0] = prefix;
+        assert_eq!(Point::from_sec(&bytes), Err(Error::InvalidPrefix(prefix)));
+    }
+}
+
+#[test]
+fn test_from_sec_invalid_length() {
+    // Test that empty, truncated and overlong inputs are rejected.
+    assert_eq!(Point::from_sec(&[]), Err(Error::InvalidLength(0)));
+    let compressed = G.to_sec(true);
+    assert_eq!(
+        Point::from_sec(&compressed[..32]),
+        Err(Error::InvalidLength(32))
+    );
+    let mut uncompressed = G.to_sec(false);
+    uncompressed.push(0);
+    assert_eq!(
+        Point::from_sec(&uncompressed),
+        Err(Error::InvalidLength(66))
+    );
+    // A compressed prefix with an uncompressed body
+    uncompressed.pop();
+    uncompressed[0] = 0x02;
+    assert_eq!(
+        Point::from_sec(&uncompressed),
+        Err(Error::InvalidLength(65))
+    );
+}
+
+#[test]
+fn test_from_sec_off_curve() {
+    // Test that an uncompressed point with a wrong y, and an x with no square root, are rejected.
+    let mut bytes = G.to_sec(false);
+    bytes[64] ^= 1;
+    assert_eq!(Point::from_sec(&bytes), Err(Error::NotOnCurve));
+
+    // x = 5 is not the x-coordinate of any secp256k1 point: 5^3 + 7 = 132 is a non-residue mod p
+    let mut bytes = vec![0x02];
+    bytes.extend_from_slice(&[0u8; 31]);
+    bytes.push(5);
+    assert_eq!(Point::from_sec(&bytes), Err(Error::NotOnCurve));
+}
+
+#[test]
+fn test_from_sec_coordinate_out_of_range() {
+    // Test that x >= p is rejected instead of being reduced.
+    let mut bytes = vec![0x02];
+    bytes.extend_from_slice(&[0xff; 32]);
+    assert_eq!(Point::from_sec(&bytes), Err(Error::OutOfRange));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn prop_sec_round_trip(k in any::<[u8; 32]>()) {
+        // Test that both encodings of random points parse back to the same point.
+        let point = Point::mul_generator(&crate::scalar::Scalar::reduce_be_bytes(&k));
+        prop_assert_eq!(Point::from_sec(&point.to_sec(true)).unwrap(), point.clone());
+        prop_assert_eq!(Point::from_sec(&point.to_sec(false)).unwrap(), point);
+    }
+}
@@ -0,0 +1,194 @@
+// Serde support, enabled with the `serde` feature.
+// Human-readable formats (JSON, TOML, ...) get hex strings: 64 digits for field elements and
+// scalars, and the compressed SEC1 encoding for points. Binary formats get the raw bytes.
+// The point at infinity is written explicitly as the single byte 0x00 ("00" in hex).
+// Public keys are compressed SEC1 like points, and ECDSA signatures are the 64 bytes r || s.
+// Recoverable signatures use the 65-byte compact encoding with the compressed-key header; the
+// flag is not part of the type, so either header is accepted when reading. BIP340 keys and
+// signatures use their 32- and 64-byte encodings.
+// Deserialization validates everything: range checks for elements, curve membership for points,
+// a finite point for public keys, and 0 < r, s < N for signatures (r < p, s < N for BIP340).
+
+use crate::curve::{Curve, CurvePoint};
+use crate::ecdsa::Signature;
+use crate::finite_fields::{Fp, PrimeField};
+use crate::keys::PublicKey;
+use crate::recovery::RecoverableSignature;
+use crate::scalar::Scalar;
+use crate::schnorr::{SchnorrSignature, XOnlyPublicKey};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+impl<F: PrimeField> Serialize for Fp<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            serializer.serialize_bytes(&self.to_be_bytes())
+        }
+    }
+}
+
+impl<'de, F: PrimeField> Deserialize<'de> for Fp<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let hex = String::deserialize(deserializer)?;
+            Fp::from_hex(&hex).map_err(de::Error::custom)
+        } else {
+            let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+            let bytes: [u8; 32] = bytes
+                .try_into()
+                .map_err(|bytes: Vec<u8>| de::Error::invalid_length(bytes.len(), &"32 bytes"))?;
+            Fp::from_be_bytes(&bytes).map_err(de::Error::custom)
+        }
+    }
+}
+
+impl<C: Curve> Serialize for CurvePoint<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.to_sec(true), serializer)
+    }
+}
+
+impl<'de, C: Curve> Deserialize<'de> for CurvePoint<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = deserialize_bytes(deserializer)?;
+        // Unlike `from_sec`, accept 0x00 here so that every serialized point deserializes
+        if bytes == [0x00] {
+            return Ok(CurvePoint::Infinity);
+        }
+        CurvePoint::from_sec(&bytes).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for PublicKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.to_sec(true), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        PublicKey::from_sec(&deserialize_bytes(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Signature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r().to_be_bytes());
+        bytes[32..].copy_from_slice(&self.s().to_be_bytes());
+        serialize_bytes(&bytes, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: [u8; 64] = deserialize_array(deserializer)?;
+        let r =
+            Scalar::from_be_bytes(bytes[..32].try_into().unwrap()).map_err(de::Error::custom)?;
+        let s =
+            Scalar::from_be_bytes(bytes[32..].try_into().unwrap()).map_err(de::Error::custom)?;
+        Signature::new(r, s).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for RecoverableSignature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.to_compact(true), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RecoverableSignature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: [u8; 65] = deserialize_array(deserializer)?;
+        RecoverableSignature::from_compact(&bytes)
+            .map(|(signature, _)| signature)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for XOnlyPublicKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for XOnlyPublicKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        XOnlyPublicKey::from_bytes(&deserialize_array(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for SchnorrSignature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SchnorrSignature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SchnorrSignature::from_bytes(&deserialize_array(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Writes bytes as a hex string in human-readable formats and as a byte string otherwise.
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&crate::hex::encode(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Reads bytes written by `serialize_bytes`.
+fn deserialize_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    if deserializer.is_human_readable() {
+        let hex = String::deserialize(deserializer)?;
+        crate::hex::decode(&hex).map_err(de::Error::custom)
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
+
+/// Reads exactly `LEN` bytes written by `serialize_bytes`.
+fn deserialize_array<'de, D: Deserializer<'de>, const LEN: usize>(
+    deserializer: D,
+) -> Result<[u8; LEN], D::Error> {
+    deserialize_bytes(deserializer)?
+        .try_into()
+        .map_err(|bytes: Vec<u8>| {
+            de::Error::invalid_length(bytes.len(), &"a fixed-length byte string")
+        })
+}
+
+/// Collects a byte string, accepting both native bytes and sequences of u8
+/// (some binary formats encode byte strings as sequences).
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a byte string")
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(bytes)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,256 @@
+use crate::ecdsa::Signature;
+use crate::finite_fields::FieldElement;
+use crate::keys::PublicKey;
+use crate::point::{G, Point};
+use crate::recovery::RecoverableSignature;
+use crate::scalar::Scalar;
+use crate::schnorr::{SchnorrSignature, XOnlyPublicKey};
+use crate::test_util::private_key;
+use num_bigint::BigInt;
+
+// Helper function to build a JSON hex string from r and s as 64-digit hex
+fn signature_json(r: &str, s: &str) -> String {
+    format!("\"{r}{s}\"")
+}
+
+//---------------------------
+// Human-Readable Formats
+//---------------------------
+
+#[test]
+fn test_field_element_json() {
+    // Test that field elements serialize to a 64-digit hex string and round-trip.
+    let a = FieldElement::new(BigInt::from(255)).unwrap();
+    let json = serde_json::to_string(&a).unwrap();
+    assert_eq!(json, format!("\"{:0>64}\"", "ff"));
+    assert_eq!(serde_json::from_str::<FieldElement>(&json).unwrap(), a);
+}
+
+#[test]
+fn test_scalar_json() {
+    // Test that scalars round-trip and values not below N are rejected.
+    let k = Scalar::new(BigInt::from(42)).unwrap();
+    let json = serde_json::to_string(&k).unwrap();
+    assert_eq!(serde_json::from_str::<Scalar>(&json).unwrap(), k);
+    let n = "\"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141\"";
+    assert!(serde_json::from_str::<Scalar>(n).is_err());
+}
+
+#[test]
+fn test_point_json() {
+    // Test that points serialize to compressed SEC hex and infinity to "00".
+    let json = serde_json::to_string(&G).unwrap();
+    assert_eq!(
+        json,
+        "\"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\""
+    );
+    assert_eq!(serde_json::from_str::<Point>(&json).unwrap(), G);
+
+    let json = serde_json::to_string(&Point::Infinity).unwrap();
+    assert_eq!(json, "\"00\"");
+    assert_eq!(
+        serde_json::from_str::<Point>(&json).unwrap(),
+        Point::Infinity
+    );
+}
+
+#[test]
+fn test_point_json_validation() {
+    // Test that off-curve, malformed and non-string inputs are rejected.
+    let off_curve = "\"020000000000000000000000000000000000000000000000000000000000000005\"";
+    assert!(serde_json::from_str::<Point>(off_curve).is_err());
+    assert!(serde_json::from_str::<Point>("\"02zz\"").is_err());
+    assert!(serde_json::from_str::<Point>("42").is_err());
+}
+
+#[test]
+fn test_nested_structures() {
+    // Test that the types compose inside derived structures, as in a config file.
+    let pair = (Scalar::one(), G.clone());
+    let json = serde_json::to_string(&pair).unwrap();
+    assert_eq!(
+        serde_json::from_str::<(Scalar, Point)>(&json).unwrap(),
+        pair
+    );
+}
+
+//------------------
+// Key Tests
+//------------------
+
+#[test]
+fn test_public_key_json() {
+    // Test that public keys serialize to compressed SEC hex, like points, and round-trip.
+    let public_key = private_key(12345).public_key().clone();
+    let json = serde_json::to_string(&public_key).unwrap();
+    assert_eq!(json, serde_json::to_string(public_key.point()).unwrap());
+    assert_eq!(
+        serde_json::from_str::<PublicKey>(&json).unwrap(),
+        public_key
+    );
+}
+
+#[test]
+fn test_public_key_rejects_infinity() {
+    // Test that the point at infinity, valid as a Point, is not a valid public key.
+    let json = serde_json::to_string(&Point::Infinity).unwrap();
+    assert!(serde_json::from_str::<Point>(&json).is_ok());
+    assert!(serde_json::from_str::<PublicKey>(&json).is_err());
+    let bytes = bincode::serialize(&Point::Infinity).unwrap();
+    assert!(bincode::deserialize::<PublicKey>(&bytes).is_err());
+}
+
+//------------------
+// Signature Tests
+//------------------
+
+#[test]
+fn test_signature_json() {
+    // Test that ECDSA signatures serialize to the hex of r || s and round-trip.
+    let signature = private_key(12345).sign(&Scalar::one());
+    let json = serde_json::to_string(&signature).unwrap();
+    assert_eq!(
+        json,
+        signature_json(&signature.r().to_hex(), &signature.s().to_hex())
+    );
+    assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), signature);
+}
+
+#[test]
+fn test_signature_json_validation() {
+    // Test that r or s equal to 0 or N, and wrong lengths, are rejected.
+    let zero = "0".repeat(64);
+    let one = format!("{:0>64}", "1");
+    let n = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+    for (r, s) in [(&*zero, &*one), (&*one, &*zero), (n, &*one), (&*one, n)] {
+        assert!(serde_json::from_str::<Signature>(&signature_json(r, s)).is_err());
+    }
+    assert!(serde_json::from_str::<Signature>(&signature_json(&one, &one)).is_ok());
+    assert!(serde_json::from_str::<Signature>(&signature_json(&one, "01")).is_err());
+}
+
+#[test]
+fn test_recoverable_signature_json() {
+    // Test that recoverable signatures serialize to the compact encoding and round-trip.
+    let signature = private_key(12345).sign_recoverable(&Scalar::one());
+    let json = serde_json::to_string(&signature).unwrap();
+    let compact = crate::hex::encode(&signature.to_compact(true));
+    assert_eq!(json, format!("\"{compact}\""));
+    assert_eq!(
+        serde_json::from_str::<RecoverableSignature>(&json).unwrap(),
+        signature
+    );
+}
+
+#[test]
+fn test_recoverable_signature_json_validation() {
+    // Test that bad headers, s = 0 and wrong lengths are rejected.
+    let mut compact = private_key(12345)
+        .sign_recoverable(&Scalar::one())
+        .to_compact(true);
+    let parse = |bytes: &[u8]| {
+        let json = format!("\"{}\"", crate::hex::encode(bytes));
+        serde_json::from_str::<RecoverableSignature>(&json)
+    };
+    assert!(parse(&compact[..64]).is_err());
+    compact[0] = 35;
+    assert!(parse(&compact).is_err());
+    compact[0] = 27;
+    assert!(parse(&compact).is_ok());
+    compact[33..].fill(0);
+    assert!(parse(&compact).is_err());
+}
+
+#[test]
+fn test_schnorr_json() {
+    // Test that x-only keys and BIP340 signatures serialize to their hex encodings and round-trip.
+    let key = private_key(12345);
+    let x_only = key.x_only_public_key();
+    let json = serde_json::to_string(&x_only).unwrap();
+    assert_eq!(
+        json,
+        format!("\"{}\"", crate::hex::encode(&x_only.to_bytes()))
+    );
+    assert_eq!(
+        serde_json::from_str::<XOnlyPublicKey>(&json).unwrap(),
+        x_only
+    );
+
+    let signature = key.sign_schnorr(b"message", &[0; 32]).unwrap();
+    let json = serde_json::to_string(&signature).unwrap();
+    assert_eq!(
+        json,
+        format!("\"{}\"", crate::hex::encode(&signature.to_bytes()))
+    );
+    assert_eq!(
+        serde_json::from_str::<SchnorrSignature>(&json).unwrap(),
+        signature
+    );
+}
+
+#[test]
+fn test_schnorr_json_validation() {
+    // Test that an x with no curve point, r = p, s = N and wrong lengths are rejected.
+    let p = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+    let n = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+    let five = format!("{:0>64}", "5");
+    assert!(serde_json::from_str::<XOnlyPublicKey>(&format!("\"{five}\"")).is_err());
+    assert!(serde_json::from_str::<XOnlyPublicKey>(&format!("\"{p}\"")).is_err());
+    assert!(serde_json::from_str::<XOnlyPublicKey>("\"0279be\"").is_err());
+    assert!(serde_json::from_str::<SchnorrSignature>(&signature_json(p, &five)).is_err());
+    assert!(serde_json::from_str::<SchnorrSignature>(&signature_json(&five, n)).is_err());
+    assert!(serde_json::from_str::<SchnorrSignature>(&signature_json(&five, &five)).is_ok());
+}
+
+//------------------
+// Binary Formats
+//------------------
+
+#[test]
+fn test_bincode_round_trip() {
+    // Test that binary formats store raw bytes: 32 for elements and 33 for points, plus a length prefix.
+    let a = FieldElement::new(BigInt::from(7)).unwrap();
+    let bytes = bincode::serialize(&a).unwrap();
+    assert_eq!(bytes.len(), 8 + 32);
+    assert_eq!(bincode::deserialize::<FieldElement>(&bytes).unwrap(), a);
+
+    let bytes = bincode::serialize(&G).unwrap();
+    assert_eq!(bytes.len(), 8 + 33);
+    assert_eq!(bincode::deserialize::<Point>(&bytes).unwrap(), G);
+
+    let bytes = bincode::serialize(&Point::Infinity).unwrap();
+    assert_eq!(
+        bincode::deserialize::<Point>(&bytes).unwrap(),
+        Point::Infinity
+    );
+}
+
+#[test]
+fn test_bincode_validation() {
+    // Test that wrong lengths and out-of-range values are rejected in binary form.
+    let short = bincode::serialize(&vec![0u8; 31]).unwrap();
+    assert!(bincode::deserialize::<FieldElement>(&short).is_err());
+    let too_large = bincode::serialize(&vec![0xffu8; 32]).unwrap();
+    assert!(bincode::deserialize::<FieldElement>(&too_large).is_err());
+    let mut point = G.to_sec(true);
+    point[0] = 0x05;
+    let bytes = bincode::serialize(&point).unwrap();
+    assert!(bincode::deserialize::<Point>(&bytes).is_err());
+}
+
+#[test]
+fn test_bincode_signature() {
+    // Test that signatures are stored as 64 raw bytes and reject s = 0.
+    let signature = private_key(12345).sign(&Scalar::one());
+    let bytes = bincode::serialize(&signature).unwrap();
+    assert_eq!(bytes.len(), 8 + 64);
+    assert_eq!(
+        bincode::deserialize::<Signature>(&bytes).unwrap(),
+        signature
+    );
+
+    let mut raw = [0u8; 64];
+    raw[31] = 1;
+    let bytes = bincode::serialize(&raw.to_vec()).unwrap();
+    assert!(bincode::deserialize::<Signature>(&bytes).is_err());
+}
@@ -0,0 +1,42 @@
+// Helpers shared by the test modules: decoding hex fixtures and building the scalars, keys and
+// signatures that several test files use.
+
+use crate::ecdsa::Signature;
+use crate::keys::PrivateKey;
+use crate::scalar::Scalar;
+use num_bigint::BigInt;
+
+/// Decodes a hex string into bytes.
+pub(crate) fn hex(s: &str) -> Vec<u8> {
+    crate::hex::decode(s).unwrap()
+}
+
+/// Decodes a hex string of a fixed length.
+pub(crate) fn bytes<const LEN: usize>(s: &str) -> [u8; LEN] {
+    hex(s).try_into().unwrap()
+}
+
+/// Parses a scalar from a hex string.
+pub(crate) fn scalar(s: &str) -> Scalar {
+    Scalar::from_hex(s).unwrap()
+}
+
+/// Builds a signature from hex r and s.
+pub(crate) fn signature(r: &str, s: &str) -> Signature {
+    Signature::new(scalar(r), scalar(s)).unwrap()
+}
+
+/// Builds a private key from a small secret.
+pub(crate) fn private_key(secret: u64) -> PrivateKey {
+    PrivateKey::new(Scalar::new(BigInt::from(secret)).unwrap()).unwrap()
+}
+
+/// Builds a private key from a 32-byte hex secret.
+pub(crate) fn key(s: &str) -> PrivateKey {
+    PrivateKey::from_bytes(&bytes(s)).unwrap()
+}
+
+/// Returns the private key of the BIP322 test vectors, which the message tests also use.
+pub(crate) fn bip322_key() -> PrivateKey {
+    key("bb051cd0dda0246f33c5a9e133ebd8e7bc02a92af6c41adc131ccd7826c5b004")
+}
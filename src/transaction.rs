@@ -0,0 +1,329 @@
+// Bitcoin transactions: just enough to build, serialize and parse the virtual transactions
+// of BIP322 and to compute segwit version 0 (BIP143) and taproot key-path (BIP341) signature
+// hashes.
+// Serialization: version (4 bytes), optionally the segwit marker 0x00 and flag 0x01, the
+// inputs, the outputs, one witness stack per input if segwit, then the lock time (4 bytes).
+// Integers are little-endian and counts and lengths are compact-size integers. The txid is
+// hash256 of the serialization without witnesses, kept here in its internal byte order (the
+// reverse of how explorers display it).
+
+use crate::error::Error;
+use crate::hash::{hash256, sha256, tagged_hash};
+use alloc::vec::Vec;
+
+/// Signature hash type of taproot signatures without a hash type byte, which commits to the
+/// same data as SIGHASH_ALL.
+pub(crate) const SIGHASH_DEFAULT: u8 = 0x00;
+
+/// Signature hash type that commits to all inputs and outputs.
+pub(crate) const SIGHASH_ALL: u8 = 0x01;
+
+/// A reference to an output of an earlier transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct OutPoint {
+    pub(crate) txid: [u8; 32],
+    pub(crate) vout: u32,
+}
+
+/// A transaction input with its witness stack.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TxIn {
+    pub(crate) previous_output: OutPoint,
+    pub(crate) script_sig: Vec<u8>,
+    pub(crate) sequence: u32,
+    pub(crate) witness: Vec<Vec<u8>>,
+}
+
+/// A transaction output: an amount in satoshis and the script that locks it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TxOut {
+    pub(crate) value: u64,
+    pub(crate) script_pubkey: Vec<u8>,
+}
+
+/// A transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Transaction {
+    pub(crate) version: i32,
+    pub(crate) inputs: Vec<TxIn>,
+    pub(crate) outputs: Vec<TxOut>,
+    pub(crate) lock_time: u32,
+}
+
+impl Transaction {
+    /// Serializes the transaction, in the segwit format if any input has a witness.
+    pub(crate) fn serialize(&self) -> Vec<u8> {
+        let has_witness = self.inputs.iter().any(|input| !input.witness.is_empty());
+        self.serialize_inner(has_witness)
+    }
+
+    /// Returns the txid: hash256 of the serialization without witnesses.
+    pub(crate) fn txid(&self) -> [u8; 32] {
+        hash256(&self.serialize_inner(false))
+    }
+
+    fn serialize_inner(&self, with_witness: bool) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        if with_witness {
+            bytes.extend_from_slice(&[0x00, 0x01]);
+        }
+        write_compact_size(&mut bytes, self.inputs.len() as u64);
+        for input in &self.inputs {
+            bytes.extend_from_slice(&input.previous_output.txid);
+            bytes.extend_from_slice(&input.previous_output.vout.to_le_bytes());
+            write_bytes(&mut bytes, &input.script_sig);
+            bytes.extend_from_slice(&input.sequence.to_le_bytes());
+        }
+        write_compact_size(&mut bytes, self.outputs.len() as u64);
+        for output in &self.outputs {
+            write_output(&mut bytes, output);
+        }
+        if with_witness {
+            for input in &self.inputs {
+                bytes.extend_from_slice(&serialize_witness(&input.witness));
+            }
+        }
+        bytes.extend_from_slice(&self.lock_time.to_le_bytes());
+        bytes
+    }
+
+    /// Parses a transaction in either format.
+    /// Returns `Error::InvalidTransaction` if the bytes are truncated, have trailing data, or
+    /// use the segwit format with no witness data.
+    pub(crate) fn parse(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let version = i32::from_le_bytes(reader.array()?);
+        // An input count of zero is the segwit marker, which must be followed by the flag 0x01
+        let mut input_count = reader.compact_size()?;
+        let segwit = input_count == 0;
+        if segwit {
+            if reader.byte()? != 0x01 {
+                return Err(Error::InvalidTransaction);
+            }
+            input_count = reader.compact_size()?;
+        }
+        let mut inputs = Vec::new();
+        for _ in 0..input_count {
+            inputs.push(TxIn {
+                previous_output: OutPoint {
+                    txid: reader.array()?,
+                    vout: u32::from_le_bytes(reader.array()?),
+                },
+                script_sig: reader.bytes()?.to_vec(),
+                sequence: u32::from_le_bytes(reader.array()?),
+                witness: Vec::new(),
+            });
+        }
+        let mut outputs = Vec::new();
+        for _ in 0..reader.compact_size()? {
+            outputs.push(TxOut {
+                value: u64::from_le_bytes(reader.array()?),
+                script_pubkey: reader.bytes()?.to_vec(),
+            });
+        }
+        if segwit {
+            for input in inputs.iter_mut() {
+                input.witness = reader.witness()?;
+            }
+            if inputs.iter().all(|input| input.witness.is_empty()) {
+                return Err(Error::InvalidTransaction);
+            }
+        }
+        let lock_time = u32::from_le_bytes(reader.array()?);
+        if reader.pos != bytes.len() {
+            return Err(Error::InvalidTransaction);
+        }
+        Ok(Transaction {
+            version,
+            inputs,
+            outputs,
+            lock_time,
+        })
+    }
+
+    /// Computes the BIP143 SIGHASH_ALL signature hash of input `index`, which spends `amount`
+    /// satoshis, for a segwit version 0 script with the given script code.
+    pub(crate) fn segwit_v0_sighash(
+        &self,
+        index: usize,
+        script_code: &[u8],
+        amount: u64,
+    ) -> [u8; 32] {
+        let mut prevouts = Vec::with_capacity(36 * self.inputs.len());
+        let mut sequences = Vec::with_capacity(4 * self.inputs.len());
+        for input in &self.inputs {
+            prevouts.extend_from_slice(&input.previous_output.txid);
+            prevouts.extend_from_slice(&input.previous_output.vout.to_le_bytes());
+            sequences.extend_from_slice(&input.sequence.to_le_bytes());
+        }
+        let mut outputs = Vec::new();
+        for output in &self.outputs {
+            write_output(&mut outputs, output);
+        }
+
+        let input = &self.inputs[index];
+        let mut preimage = Vec::with_capacity(160 + script_code.len());
+        preimage.extend_from_slice(&self.version.to_le_bytes());
+        preimage.extend_from_slice(&hash256(&prevouts));
+        preimage.extend_from_slice(&hash256(&sequences));
+        preimage.extend_from_slice(&input.previous_output.txid);
+        preimage.extend_from_slice(&input.previous_output.vout.to_le_bytes());
+        write_bytes(&mut preimage, script_code);
+        preimage.extend_from_slice(&amount.to_le_bytes());
+        preimage.extend_from_slice(&input.sequence.to_le_bytes());
+        preimage.extend_from_slice(&hash256(&outputs));
+        preimage.extend_from_slice(&self.lock_time.to_le_bytes());
+        preimage.extend_from_slice(&(SIGHASH_ALL as u32).to_le_bytes());
+        hash256(&preimage)
+    }
+
+    /// Computes the BIP341 signature hash of a key-path spend of input `index`, given the
+    /// outputs spent by all inputs, for SIGHASH_DEFAULT or SIGHASH_ALL (`hash_type`).
+    /// The other hash types and the annex are not supported.
+    pub(crate) fn taproot_key_spend_sighash(
+        &self,
+        index: usize,
+        spent_outputs: &[TxOut],
+        hash_type: u8,
+    ) -> [u8; 32] {
+        let mut prevouts = Vec::with_capacity(36 * self.inputs.len());
+        let mut sequences = Vec::with_capacity(4 * self.inputs.len());
+        for input in &self.inputs {
+            prevouts.extend_from_slice(&input.previous_output.txid);
+            prevouts.extend_from_slice(&input.previous_output.vout.to_le_bytes());
+            sequences.extend_from_slice(&input.sequence.to_le_bytes());
+        }
+        let mut amounts = Vec::with_capacity(8 * spent_outputs.len());
+        let mut script_pubkeys = Vec::new();
+        for output in spent_outputs {
+            amounts.extend_from_slice(&output.value.to_le_bytes());
+            write_bytes(&mut script_pubkeys, &output.script_pubkey);
+        }
+        let mut outputs = Vec::new();
+        for output in &self.outputs {
+            write_output(&mut outputs, output);
+        }
+
+        // Epoch 0, then the message of BIP341 with spend type 0 (key path, no annex)
+        let mut message = Vec::with_capacity(175);
+        message.extend_from_slice(&[0x00, hash_type]);
+        message.extend_from_slice(&self.version.to_le_bytes());
+        message.extend_from_slice(&self.lock_time.to_le_bytes());
+        message.extend_from_slice(&sha256(&prevouts));
+        message.extend_from_slice(&sha256(&amounts));
+        message.extend_from_slice(&sha256(&script_pubkeys));
+        message.extend_from_slice(&sha256(&sequences));
+        message.extend_from_slice(&sha256(&outputs));
+        message.push(0x00);
+        message.extend_from_slice(&(index as u32).to_le_bytes());
+        tagged_hash("TapSighash", &[&message])
+    }
+}
+
+/// Appends a Bitcoin compact-size integer: one byte below 0xfd, otherwise a marker byte
+/// followed by the value in 2, 4 or 8 little-endian bytes.
+pub(crate) fn write_compact_size(bytes: &mut Vec<u8>, value: u64) {
+    match value {
+        0..0xfd => bytes.push(value as u8),
+        0xfd..=0xffff => {
+            bytes.push(0xfd);
+            bytes.extend_from_slice(&(value as u16).to_le_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            bytes.push(0xfe);
+            bytes.extend_from_slice(&(value as u32).to_le_bytes());
+        }
+        _ => {
+            bytes.push(0xff);
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+}
+
+/// Appends a byte string preceded by its compact-size length.
+fn write_bytes(bytes: &mut Vec<u8>, data: &[u8]) {
+    write_compact_size(bytes, data.len() as u64);
+    bytes.extend_from_slice(data);
+}
+
+fn write_output(bytes: &mut Vec<u8>, output: &TxOut) {
+    bytes.extend_from_slice(&output.value.to_le_bytes());
+    write_bytes(bytes, &output.script_pubkey);
+}
+
+/// Serializes a witness stack: the item count, then each item with its length.
+pub(crate) fn serialize_witness(witness: &[Vec<u8>]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    write_compact_size(&mut bytes, witness.len() as u64);
+    for item in witness {
+        write_bytes(&mut bytes, item);
+    }
+    bytes
+}
+
+/// Parses a serialized witness stack with nothing after it.
+/// Returns `Error::InvalidTransaction` if it is truncated or has trailing bytes.
+pub(crate) fn parse_witness(bytes: &[u8]) -> Result<Vec<Vec<u8>>, Error> {
+    let mut reader = Reader { bytes, pos: 0 };
+    let witness = reader.witness()?;
+    if reader.pos != bytes.len() {
+        return Err(Error::InvalidTransaction);
+    }
+    Ok(witness)
+}
+
+/// A cursor over serialized data; every read fails with `Error::InvalidTransaction` past the end.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        if count > self.bytes.len() - self.pos {
+            return Err(Error::InvalidTransaction);
+        }
+        let slice = &self.bytes[self.pos..self.pos + count];
+        self.pos += count;
+        Ok(slice)
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn array<const LEN: usize>(&mut self) -> Result<[u8; LEN], Error> {
+        Ok(self.take(LEN)?.try_into().unwrap())
+    }
+
+    /// Reads a compact-size integer, rejecting encodings that are not minimal.
+    fn compact_size(&mut self) -> Result<u64, Error> {
+        let (value, min) = match self.byte()? {
+            0xfd => (u16::from_le_bytes(self.array()?) as u64, 0xfd),
+            0xfe => (u32::from_le_bytes(self.array()?) as u64, 0x1_0000),
+            0xff => (u64::from_le_bytes(self.array()?), 0x1_0000_0000),
+            byte => return Ok(byte as u64),
+        };
+        if value < min {
+            return Err(Error::InvalidTransaction);
+        }
+        Ok(value)
+    }
+
+    /// Reads a byte string preceded by its compact-size length.
+    fn bytes(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.compact_size()?;
+        // A length that does not fit in a usize cannot fit in the input either
+        self.take(usize::try_from(len).map_err(|_| Error::InvalidTransaction)?)
+    }
+
+    fn witness(&mut self) -> Result<Vec<Vec<u8>>, Error> {
+        let count = self.compact_size()?;
+        let mut witness = Vec::new();
+        for _ in 0..count {
+            witness.push(self.bytes()?.to_vec());
+        }
+        Ok(witness)
+    }
+}
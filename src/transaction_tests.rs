@@ -0,0 +1,111 @@
+use crate::error::Error;
+use crate::transaction::{
+    OutPoint, Transaction, TxIn, TxOut, parse_witness, serialize_witness, write_compact_size,
+};
+
+// Helper function for a one-input, one-output transaction with the given witness
+fn transaction(witness: Vec<Vec<u8>>) -> Transaction {
+    Transaction {
+        version: 2,
+        inputs: vec![TxIn {
+            previous_output: OutPoint {
+                txid: [0xab; 32],
+                vout: 1,
+            },
+            script_sig: vec![0x51],
+            sequence: 0xffff_fffe,
+            witness,
+        }],
+        outputs: vec![TxOut {
+            value: 50_000,
+            script_pubkey: vec![0x6a],
+        }],
+        lock_time: 800_000,
+    }
+}
+
+//------------------------------
+// Encoding Tests
+//------------------------------
+
+#[test]
+fn test_compact_size_boundaries() {
+    // Test the one, three, five and nine byte encodings at their boundaries.
+    let cases: [(u64, &str); 6] = [
+        (0, "00"),
+        (0xfc, "fc"),
+        (0xfd, "fdfd00"),
+        (0xffff, "fdffff"),
+        (0x1_0000, "fe00000100"),
+        (0x1_0000_0000, "ff0000000001000000"),
+    ];
+    for (value, hex) in cases {
+        let mut bytes = Vec::new();
+        write_compact_size(&mut bytes, value);
+        assert_eq!(crate::hex::encode(&bytes), hex);
+    }
+}
+
+#[test]
+fn test_transaction_round_trip() {
+    // Test that legacy and segwit serializations parse back, and that the txid ignores the witness.
+    let legacy = transaction(Vec::new());
+    let segwit = transaction(vec![vec![1, 2, 3], Vec::new()]);
+    assert_eq!(Transaction::parse(&legacy.serialize()).unwrap(), legacy);
+    assert_eq!(Transaction::parse(&segwit.serialize()).unwrap(), segwit);
+    assert_eq!(segwit.serialize().len(), legacy.serialize().len() + 2 + 6);
+    assert_eq!(segwit.txid(), legacy.txid());
+}
+
+#[test]
+fn test_witness_round_trip() {
+    // Test the witness stack encoding: a count, then each item with its length.
+    let witness = vec![vec![0xaa; 3], Vec::new()];
+    let bytes = serialize_witness(&witness);
+    assert_eq!(crate::hex::encode(&bytes), "0203aaaaaa00");
+    assert_eq!(parse_witness(&bytes).unwrap(), witness);
+}
+
+//------------------------------
+// Parsing Error Tests
+//------------------------------
+
+#[test]
+fn test_parse_errors() {
+    // Test that truncated data, trailing bytes, non-minimal lengths and empty segwit
+    // witnesses are rejected.
+    let bytes = transaction(vec![vec![1]]).serialize();
+    assert_eq!(
+        Transaction::parse(&bytes[..bytes.len() - 1]),
+        Err(Error::InvalidTransaction)
+    );
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        Transaction::parse(&trailing),
+        Err(Error::InvalidTransaction)
+    );
+
+    // The segwit marker with a flag other than 0x01, and with no witness items at all
+    let mut bad_flag = bytes.clone();
+    bad_flag[5] = 0x02;
+    assert_eq!(
+        Transaction::parse(&bad_flag),
+        Err(Error::InvalidTransaction)
+    );
+    let mut empty = transaction(Vec::new()).serialize();
+    empty.splice(4..4, [0x00, 0x01]);
+    let lock_time = empty.len() - 4;
+    empty.insert(lock_time, 0x00);
+    assert_eq!(Transaction::parse(&empty), Err(Error::InvalidTransaction));
+
+    assert_eq!(
+        parse_witness(&[0x01, 0xfd, 0x01, 0x00, 0xaa]),
+        Err(Error::InvalidTransaction)
+    );
+    assert_eq!(
+        parse_witness(&[0x01, 0x02, 0xaa]),
+        Err(Error::InvalidTransaction)
+    );
+    assert_eq!(parse_witness(&[0x00, 0x00]), Err(Error::InvalidTransaction));
+}